@@ -41,6 +41,7 @@ fn get_customers() -> Vec<Customer> {
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 struct Order {
     id: u32,
     customer_id: u32,
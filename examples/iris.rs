@@ -4,6 +4,7 @@ use irisdata::{Species, IRIS_DATA};
 use joinable::{JoinableGrouped, RHS};
 
 #[derive(Debug)]
+#[allow(dead_code)]
 struct IrisData {
     species: Species,
     common_name: &'static str,
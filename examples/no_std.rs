@@ -0,0 +1,37 @@
+//! Builds against `joinable` without the standard library, to check that the crate compiles with
+//! only `alloc`:
+//!
+//! ```sh
+//! cargo build --example no_std --no-default-features
+//! ```
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use joinable::{JoinableGrouped, RHS};
+
+pub struct Order {
+    pub customer_id: u32,
+    pub amount_cents: u32,
+}
+
+/// The total spent by each customer that has placed orders.
+pub fn spend_by_customer(customers: &[u32], orders: &[Order]) -> Vec<(u32, u32)> {
+    customers
+        .iter()
+        .copied()
+        .inner_join_grouped(orders, |c, o| (*c).cmp(&o.customer_id))
+        .map(|(c, os)| (c, os.iter().map(|o| o.amount_cents).sum()))
+        .collect()
+}
+
+/// Customers that have never placed an order, given orders sorted by customer.
+pub fn inactive_customers<'a>(customers: &'a [u32], sorted_orders: &'a [Order]) -> Vec<&'a u32> {
+    customers
+        .iter()
+        .anti_join(RHS::new_sorted(sorted_orders), |c, o| {
+            (*c).cmp(&o.customer_id)
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+/// Joins left records against a single aggregate computed from all of RHS, keeping only records
+/// that match it.
+///
+/// `agg` reduces the whole of RHS to an `A` (eg, its mean), and the specified predicate returns a
+/// [core::cmp::Ordering] comparing a left record and that aggregate. The aggregate is computed
+/// lazily, once, when the join is first iterated, and each match is yielded as `(&L, A)`. If `A` is
+/// expensive to clone, have `agg` return an [Rc](alloc::rc::Rc).
+pub fn inner_join_vs_aggregate<'a, LIt, L, R, A, F, P>(
+    left: LIt,
+    rhs: &'a [R],
+    agg: F,
+    predicate: P,
+) -> JoinedVsAggregate<'a, LIt::IntoIter, R, A, F, P>
+where
+    LIt: IntoIterator<Item = &'a L>,
+    L: 'a,
+    A: Clone,
+    F: Fn(&[R]) -> A,
+    P: Fn(&L, &A) -> core::cmp::Ordering,
+{
+    JoinedVsAggregate {
+        lhs_iter: left.into_iter(),
+        rhs,
+        agg,
+        aggregate: None,
+        predicate,
+    }
+}
+
+/// The intermediate result of [inner_join_vs_aggregate] that will yield `(&L, A)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedVsAggregate<'a, LIt, R, A, F, P> {
+    lhs_iter: LIt,
+    rhs: &'a [R],
+
+    /// Computes the aggregate of RHS: Fn(&[R]) -> A
+    agg: F,
+
+    /// The aggregate, once computed
+    aggregate: Option<A>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, A, F, P> JoinedVsAggregate<'a, LIt, R, A, F, P>
+where
+    F: Fn(&[R]) -> A,
+{
+    /// Returns the aggregate of RHS, computing it if the join hasn't already.
+    pub fn aggregate(&mut self) -> &A {
+        let (rhs, agg) = (self.rhs, &self.agg);
+        self.aggregate.get_or_insert_with(|| agg(rhs))
+    }
+}
+
+impl<'a, LIt, L, R, A, F, P> Iterator for JoinedVsAggregate<'a, LIt, R, A, F, P>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    A: Clone,
+    F: Fn(&[R]) -> A,
+    P: Fn(&L, &A) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, A);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+            let predicate = &self.predicate;
+            let aggregate = {
+                let (rhs, agg) = (self.rhs, &self.agg);
+                self.aggregate.get_or_insert_with(|| agg(rhs))
+            };
+
+            if predicate(left, aggregate).is_eq() {
+                return Some((left, aggregate.clone()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_inner_join_vs_aggregate() {
+    use core::cell::Cell;
+
+    let left = [(1.0, "a"), (2.4, "b"), (3.0, "c"), (2.6, "d")];
+    let right = [1.0, 2.0, 3.0, 4.0];
+
+    let calls = Cell::new(0);
+    let mean = |rs: &[f64]| {
+        calls.set(calls.get() + 1);
+        rs.iter().sum::<f64>() / rs.len() as f64
+    };
+
+    // Match lefts within half a unit of the right-side mean
+    let near_mean = |l: &(f64, &str), m: &f64| {
+        if (l.0 - m).abs() < 0.5 {
+            core::cmp::Ordering::Equal
+        } else {
+            l.0.total_cmp(m)
+        }
+    };
+
+    let mut joined = inner_join_vs_aggregate(&left, &right, mean, near_mean);
+    assert_eq!(calls.get(), 0);
+
+    assert_eq!(joined.next(), Some((&(2.4, "b"), 2.5)));
+    assert_eq!(joined.next(), Some((&(2.6, "d"), 2.5)));
+    assert_eq!(joined.next(), None);
+    assert_eq!(*joined.aggregate(), 2.5);
+    assert_eq!(calls.get(), 1);
+}
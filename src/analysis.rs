@@ -0,0 +1,178 @@
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+use crate::RHS;
+
+/// Returns the keys that appear more than once in `left`, in the order they were first
+/// duplicated.
+///
+/// Unexpected duplicate keys on the left are a common source of join fan-out, so this can be run
+/// before a join to check cardinality assumptions.
+pub fn detect_left_duplicates<L, K, I, F>(left: I, key: F) -> Vec<K>
+where
+    I: IntoIterator<Item = L>,
+    F: Fn(&L) -> K,
+    K: Hash + Eq + Clone,
+{
+    let mut seen = KeyCounts::default();
+    for l in left {
+        seen.add(key(&l));
+    }
+
+    seen.duplicates
+}
+
+/// Builds the inverse of a join: for each record in `rhs` that matched, the records from `left`
+/// that matched it, keyed by the right record's index in `rhs`.
+///
+/// The specified predicate returns a [core::cmp::Ordering] comparing left and right records. Left
+/// records are cloned, since one may match several right records; each list keeps LHS order.
+/// Right records without matches are not included.
+pub fn build_inverted_index<'a, L, R, I, P>(
+    left: I,
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+) -> HashMap<usize, Vec<L>>
+where
+    I: IntoIterator<Item = L>,
+    L: Clone,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    let rhs = rhs.into();
+    let mut index: HashMap<usize, Vec<L>> = HashMap::new();
+
+    for l in left {
+        let mut range = rhs.get_range(&l, &predicate);
+        while let Some((i, _)) = rhs.next_match(&l, &predicate, &mut range) {
+            index
+                .entry(rhs.original_index(i))
+                .or_default()
+                .push(l.clone());
+        }
+    }
+
+    index
+}
+
+/// Counts of keys seen so far, along with those seen more than once.
+struct KeyCounts<K> {
+    counts: HashMap<K, usize>,
+    duplicates: Vec<K>,
+}
+
+impl<K> Default for KeyCounts<K> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+            duplicates: Vec::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyCounts<K> {
+    fn add(&mut self, key: K) {
+        let count = self.counts.entry(key.clone()).or_insert(0);
+        *count += 1;
+
+        if *count == 2 {
+            self.duplicates.push(key);
+        }
+    }
+}
+
+/// A handle listing the left keys that a checked join has seen more than once.
+///
+/// The handle is populated as the join is iterated, so it only reflects the left records pulled
+/// so far.
+pub struct DuplicateKeys<K> {
+    seen: Rc<RefCell<KeyCounts<K>>>,
+}
+
+impl<K: Clone> DuplicateKeys<K> {
+    /// The keys seen more than once so far, in the order they were first duplicated.
+    pub fn keys(&self) -> Vec<K> {
+        self.seen.borrow().duplicates.clone()
+    }
+
+    /// Whether any key has been seen more than once so far.
+    pub fn is_empty(&self) -> bool {
+        self.seen.borrow().duplicates.is_empty()
+    }
+}
+
+/// Wraps a left-hand iterator, counting the key of each value that passes through it.
+pub struct CountedKeys<LIt, F, K> {
+    lhs_iter: LIt,
+    key: F,
+    seen: Rc<RefCell<KeyCounts<K>>>,
+}
+
+impl<LIt, F, K> CountedKeys<LIt, F, K> {
+    pub(crate) fn new(lhs_iter: LIt, key: F) -> (Self, DuplicateKeys<K>) {
+        let seen = Rc::new(RefCell::new(KeyCounts::default()));
+
+        let handle = DuplicateKeys { seen: seen.clone() };
+        (
+            Self {
+                lhs_iter,
+                key,
+                seen,
+            },
+            handle,
+        )
+    }
+}
+
+impl<LIt, F, K> Iterator for CountedKeys<LIt, F, K>
+where
+    LIt: Iterator,
+    F: Fn(&LIt::Item) -> K,
+    K: Hash + Eq + Clone,
+{
+    type Item = LIt::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let l = self.lhs_iter.next()?;
+        self.seen.borrow_mut().add((self.key)(&l));
+        Some(l)
+    }
+}
+
+#[test]
+fn test_detect_left_duplicates() {
+    let left = [(1, "a"), (2, "b"), (1, "c"), (3, "d"), (2, "e"), (1, "f")];
+
+    assert_eq!(detect_left_duplicates(&left, |l| l.0), vec![1, 2]);
+    assert!(detect_left_duplicates(&left, |l| l.1).is_empty());
+}
+
+#[test]
+fn test_build_inverted_index() {
+    // (customer, product id)
+    let orders = [("ann", 2), ("bob", 1), ("cy", 2), ("ann", 3)];
+    let products = [(1, "apple"), (2, "banana"), (3, "cherry"), (4, "durian")];
+
+    let index = build_inverted_index(orders, &products[..], |o, p| o.1.cmp(&p.0));
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(index[&0], vec![("bob", 1)]);
+    assert_eq!(index[&1], vec![("ann", 2), ("cy", 2)]);
+    assert_eq!(index[&2], vec![("ann", 3)]);
+    assert!(!index.contains_key(&3));
+
+    // Indices refer to the wrapped slice, not to its sorted order
+    let products = [(4, "durian"), (3, "cherry"), (2, "banana"), (1, "apple")];
+    let partial = crate::PartialSort::new(&products, |a, b| a.0.cmp(&b.0));
+    let index = build_inverted_index(orders, RHS::new_partially_sorted(&partial), |o, p| {
+        o.1.cmp(&p.0)
+    });
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(index[&3], vec![("bob", 1)]);
+    assert_eq!(index[&2], vec![("ann", 2), ("cy", 2)]);
+    assert_eq!(index[&1], vec![("ann", 3)]);
+    assert!(!index.contains_key(&0));
+}
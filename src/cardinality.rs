@@ -0,0 +1,285 @@
+use alloc::vec::Vec;
+
+use crate::RHS;
+
+/// The expected relationship between records in left and right for a
+/// [validated join](crate::Joinable::inner_join_validated).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cardinality {
+    /// Each left record matches at most one right record, and vice versa.
+    OneToOne,
+
+    /// Each right record matches at most one left record.
+    OneToMany,
+
+    /// Each left record matches at most one right record.
+    ManyToOne,
+
+    /// Any number of matches is allowed.
+    ManyToMany,
+}
+
+impl Cardinality {
+    fn unique_left(self) -> bool {
+        matches!(self, Cardinality::OneToOne | Cardinality::OneToMany)
+    }
+
+    fn unique_right(self) -> bool {
+        matches!(self, Cardinality::OneToOne | Cardinality::ManyToOne)
+    }
+}
+
+/// A violation of the expected [Cardinality] found during a validated join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardinalityError {
+    /// The left record at this position in LHS matched more than one right record.
+    MultipleRights { left_index: usize },
+
+    /// The right record at this position in RHS matched more than one left record.
+    MultipleLefts { right_index: usize },
+}
+
+impl core::fmt::Display for CardinalityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CardinalityError::MultipleRights { left_index } => {
+                write!(f, "left record {left_index} matched multiple right records")
+            }
+            CardinalityError::MultipleLefts { right_index } => {
+                write!(
+                    f,
+                    "right record {right_index} matched multiple left records"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for CardinalityError {}
+
+/// The intermediate result of a validated inner join that will yield
+/// `Result<(&L, &R), CardinalityError>` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedValidated<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+    cardinality: Cardinality,
+
+    /// The current LHS value, its position in LHS, and whether it has matched yet
+    current_left: Option<(&'a L, usize, bool)>,
+    rhs_range: (usize, usize),
+
+    /// The number of LHS values pulled so far
+    lefts_seen: usize,
+
+    /// Which RHS values have matched by their original position, for cardinalities requiring
+    /// unique left matches
+    rights_matched: Vec<bool>,
+
+    /// Set once a violation has been yielded, after which iteration stops
+    failed: bool,
+}
+
+impl<'a, LIt, R, P, L> JoinedValidated<'a, LIt, R, P, L> {
+    pub(crate) fn new(
+        lhs_iter: LIt,
+        rhs: RHS<'a, R>,
+        predicate: P,
+        cardinality: Cardinality,
+    ) -> Self {
+        let rights_matched = if cardinality.unique_left() {
+            vec![false; rhs.len()]
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            lhs_iter,
+            rhs,
+            predicate,
+            cardinality,
+            current_left: None,
+            rhs_range: (1, 0),
+            lefts_seen: 0,
+            rights_matched,
+            failed: false,
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedValidated<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = Result<(&'a L, &'a R), CardinalityError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        loop {
+            if let Some((left, left_index, matched)) = self.current_left.as_mut() {
+                if let Some((i, r)) =
+                    self.rhs
+                        .next_match(*left, &self.predicate, &mut self.rhs_range)
+                {
+                    if *matched && self.cardinality.unique_right() {
+                        self.failed = true;
+                        let left_index = *left_index;
+                        return Some(Err(CardinalityError::MultipleRights { left_index }));
+                    }
+
+                    if self.cardinality.unique_left() {
+                        let right_index = self.rhs.original_index(i);
+                        if self.rights_matched[right_index] {
+                            self.failed = true;
+                            return Some(Err(CardinalityError::MultipleLefts { right_index }));
+                        }
+                        self.rights_matched[right_index] = true;
+                    }
+
+                    *matched = true;
+                    return Some(Ok((*left, r)));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+            self.rhs_range = self.rhs.get_range(left, &self.predicate);
+            self.current_left = Some((left, self.lefts_seen, false));
+            self.lefts_seen += 1;
+        }
+    }
+}
+
+#[test]
+fn test_validated_one_to_one() {
+    use crate::Joinable;
+    let left = [1, 2, 3];
+    let right = [(1, "un"), (3, "trois")];
+
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::OneToOne)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(joined, Ok(vec![(&1, &(1, "un")), (&3, &(3, "trois"))]));
+
+    let right = [(1, "un"), (3, "trois"), (3, "tres")];
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::OneToOne)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        joined,
+        Err(CardinalityError::MultipleRights { left_index: 2 })
+    );
+
+    let left = [1, 3, 1];
+    let right = [(1, "un"), (3, "trois")];
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::OneToOne)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        joined,
+        Err(CardinalityError::MultipleLefts { right_index: 0 })
+    );
+
+    // Positions refer to the wrapped slice, not to its sorted order
+    let right = [(3, "trois"), (1, "un")];
+    let partial = crate::PartialSort::new(&right, |a, b| a.0.cmp(&b.0));
+    let joined = left
+        .iter()
+        .inner_join_validated(
+            RHS::new_partially_sorted(&partial),
+            |l, r| l.cmp(&r.0),
+            Cardinality::OneToOne,
+        )
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        joined,
+        Err(CardinalityError::MultipleLefts { right_index: 1 })
+    );
+}
+
+#[test]
+fn test_validated_one_to_many() {
+    use crate::Joinable;
+    let left = [1, 2];
+    let right = [(1, "un"), (2, "deux"), (1, "uno")];
+
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::OneToMany)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        joined,
+        Ok(vec![
+            (&1, &(1, "un")),
+            (&1, &(1, "uno")),
+            (&2, &(2, "deux"))
+        ])
+    );
+
+    let left = [1, 2, 1];
+    let mut joined = left.iter().inner_join_validated(
+        RHS::new_sorted(&[(1, "un"), (1, "uno"), (2, "deux")]),
+        |l, r| l.cmp(&r.0),
+        Cardinality::OneToMany,
+    );
+    assert_eq!(joined.next(), Some(Ok((&1, &(1, "un")))));
+    assert_eq!(joined.next(), Some(Ok((&1, &(1, "uno")))));
+    assert_eq!(joined.next(), Some(Ok((&2, &(2, "deux")))));
+    assert_eq!(
+        joined.next(),
+        Some(Err(CardinalityError::MultipleLefts { right_index: 0 }))
+    );
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_validated_many_to_one() {
+    use crate::Joinable;
+    let left = [1, 2, 1];
+    let right = [(1, "un"), (2, "deux")];
+
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::ManyToOne)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        joined,
+        Ok(vec![(&1, &(1, "un")), (&2, &(2, "deux")), (&1, &(1, "un"))])
+    );
+
+    let right = [(1, "un"), (2, "deux"), (1, "uno")];
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::ManyToOne)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        joined,
+        Err(CardinalityError::MultipleRights { left_index: 0 })
+    );
+}
+
+#[test]
+fn test_validated_many_to_many() {
+    use crate::Joinable;
+    let left = [1, 2, 1];
+    let right = [(1, "un"), (2, "deux"), (1, "uno")];
+
+    let joined = left
+        .iter()
+        .inner_join_validated(&right[..], |l, r| l.cmp(&r.0), Cardinality::ManyToMany)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(joined.len(), 5);
+}
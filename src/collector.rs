@@ -0,0 +1,92 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// A destination for join results, used by `collect_with` on joins such as
+/// [inner_join](crate::Joinable::inner_join).
+///
+/// Unlike [FromIterator], a collector can be created ahead of time and may be anything that
+/// accepts items one at a time: a deduplicating set, a running count, or a writer that streams
+/// results out without storing them.
+pub trait Collector<Item> {
+    /// Accepts the next result of a join.
+    fn push(&mut self, item: Item);
+}
+
+impl<T> Collector<T> for Vec<T> {
+    fn push(&mut self, item: T) {
+        Vec::push(self, item);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> Collector<T> for HashSet<T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    fn push(&mut self, item: T) {
+        self.insert(item);
+    }
+}
+
+impl<T, C> Collector<T> for &mut C
+where
+    C: Collector<T> + ?Sized,
+{
+    fn push(&mut self, item: T) {
+        (**self).push(item);
+    }
+}
+
+/// Pushes every item of `iter` into `collector`, returning the collector.
+pub(crate) fn collect_with<I, C>(iter: I, mut collector: C) -> C
+where
+    I: Iterator,
+    C: Collector<I::Item>,
+{
+    for item in iter {
+        collector.push(item);
+    }
+    collector
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_collect_with() {
+    use crate::{Joinable, JoinableGrouped};
+    let left = [1, 2, 2, 3];
+    let right = [(1, "un"), (2, "deux"), (2, "dos"), (4, "quatre")];
+
+    struct Count(usize);
+    impl<T> Collector<T> for Count {
+        fn push(&mut self, _: T) {
+            self.0 += 1;
+        }
+    }
+
+    let count = left
+        .iter()
+        .inner_join(&right[..], |l, r| l.cmp(&r.0))
+        .collect_with(Count(0));
+    assert_eq!(count.0, 5);
+
+    let matched = left
+        .iter()
+        .inner_join(&right[..], |l, r| l.cmp(&r.0))
+        .map(|(l, _)| l)
+        .collect::<HashSet<_>>();
+    let semi = left
+        .iter()
+        .semi_join(&right[..], |l, r| (*l).cmp(&r.0))
+        .collect_with(HashSet::new());
+    assert_eq!(semi, matched);
+
+    let mut out = vec![(&0, &(0, "zéro"))];
+    left.iter()
+        .inner_join(&right[..], |l, r| l.cmp(&r.0))
+        .collect_with(&mut out);
+    assert_eq!(out.len(), 6);
+}
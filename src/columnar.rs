@@ -0,0 +1,101 @@
+use crate::RHS;
+
+/// Joins two columns of keys, yielding `(usize, usize)` index pairs of matching keys.
+///
+/// This is useful for struct-of-arrays data, where the keys live in their own slices parallel to
+/// the value columns: the yielded indices can be used to look up the values directly, without
+/// building temporary row structs to join. `cmp` compares a left key against a right key.
+///
+/// `right_keys` may be [sorted](RHS::Sorted), in which case they are binary searched.
+pub fn indexed_key_join<'a, LK, RK, C>(
+    left_keys: &'a [LK],
+    right_keys: impl Into<RHS<'a, RK>>,
+    cmp: C,
+) -> IndexedKeyJoin<'a, LK, RK, C>
+where
+    C: Fn(&LK, &RK) -> core::cmp::Ordering,
+{
+    IndexedKeyJoin {
+        left_keys,
+        right_keys: right_keys.into(),
+        cmp,
+        left_pos: 0,
+        rhs_range: (1, 0),
+    }
+}
+
+/// The intermediate result of [indexed_key_join] that will yield `(usize, usize)` index pairs.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct IndexedKeyJoin<'a, LK, RK, C> {
+    left_keys: &'a [LK],
+    right_keys: RHS<'a, RK>,
+    cmp: C,
+
+    /// Index of the next left key to search for.
+    left_pos: usize,
+
+    /// The remaining range of right keys to search for the previous left key.
+    rhs_range: (usize, usize),
+}
+
+impl<'a, LK, RK, C> Iterator for IndexedKeyJoin<'a, LK, RK, C>
+where
+    C: Fn(&LK, &RK) -> core::cmp::Ordering,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.left_pos > 0 {
+                let i = self.left_pos - 1;
+                let left = &self.left_keys[i];
+                if let Some((j, _)) =
+                    self.right_keys
+                        .next_match(left, &self.cmp, &mut self.rhs_range)
+                {
+                    return Some((i, self.right_keys.original_index(j)));
+                }
+            }
+
+            // No matches remain for this left key; move on to the next one
+            let left = self.left_keys.get(self.left_pos)?;
+            self.rhs_range = self.right_keys.get_range(left, &self.cmp);
+            self.left_pos += 1;
+        }
+    }
+}
+
+#[test]
+fn test_indexed_key_join() {
+    let left_ids = [3, 1, 4, 1, 5];
+    let right_ids = [1, 5, 9, 1];
+
+    let pairs = indexed_key_join(&left_ids, &right_ids[..], |l, r| l.cmp(r)).collect::<Vec<_>>();
+
+    assert_eq!(pairs, vec![(1, 0), (1, 3), (3, 0), (3, 3), (4, 1)]);
+}
+
+#[test]
+fn test_indexed_key_join_sorted() {
+    let left_ids = [3, 1, 4, 1, 5];
+    let right_ids = [1, 1, 5, 9];
+    let right_names = ["un", "uno", "cinq", "neuf"];
+
+    let names = indexed_key_join(&left_ids, RHS::Sorted(&right_ids), |l, r| l.cmp(r))
+        .map(|(i, j)| (left_ids[i], right_names[j]))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        names,
+        vec![(1, "un"), (1, "uno"), (1, "un"), (1, "uno"), (5, "cinq")]
+    );
+
+    // Indices refer to the wrapped slice, not to its sorted order
+    let right_ids = (0..40).rev().collect::<Vec<_>>();
+    let partial = crate::PartialSort::new(&right_ids, |a, b| a.cmp(b));
+    let pairs = indexed_key_join(&[39, 5], RHS::new_partially_sorted(&partial), |l, r| {
+        l.cmp(r)
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(pairs, vec![(0, 0), (1, 34)]);
+}
@@ -0,0 +1,133 @@
+use core::cell::Cell;
+use core::cmp::Ordering;
+
+use crate::RHS;
+
+/// Joins the records from left and RHS as [inner_join](crate::Joinable::inner_join) does, with a
+/// predicate that may fail.
+///
+/// The specified predicate returns a `Result` holding a [core::cmp::Ordering] comparing left and
+/// right records, such as when comparing requires parsing. Each match is yielded as
+/// `Ok((&L, &R))`. The first `Err` returned by the predicate is yielded in place of any further
+/// results, after which iteration stops.
+pub fn try_inner_join<'a, LIt, L, R, P, E>(
+    left: LIt,
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+) -> JoinedTryInner<'a, LIt::IntoIter, R, P, L>
+where
+    LIt: IntoIterator<Item = &'a L>,
+    L: 'a,
+    P: Fn(&L, &R) -> Result<Ordering, E>,
+{
+    JoinedTryInner {
+        lhs_iter: left.into_iter(),
+        current_left: None,
+        rhs: rhs.into(),
+        rhs_range: (1, 0),
+        predicate,
+        failed: false,
+    }
+}
+
+/// The intermediate result of [try_inner_join] that will yield `Result<(&L, &R), E>` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedTryInner<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+
+    /// A fallible comparison predicate: Fn(&L, &R) -> Result<Ordering, E>
+    predicate: P,
+
+    /// Set once an error has been yielded, after which iteration stops
+    failed: bool,
+}
+
+impl<'a, LIt, R, P, L, E> Iterator for JoinedTryInner<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> Result<Ordering, E>,
+{
+    type Item = Result<(&'a L, &'a R), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        // Searches use an infallible predicate, keeping the first error to be checked afterwards
+        let error = Cell::new(None);
+        let predicate = |l: &L, r: &R| match (self.predicate)(l, r) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                let first = error.take().unwrap_or(e);
+                error.set(Some(first));
+                Ordering::Less
+            }
+        };
+
+        loop {
+            let found = match self.current_left {
+                Some(left) => self
+                    .rhs
+                    .next_match(left, &predicate, &mut self.rhs_range)
+                    .map(|(_, r)| (left, r)),
+                None => None,
+            };
+
+            if let Some(e) = error.take() {
+                self.failed = true;
+                return Some(Err(e));
+            }
+            if let Some(pair) = found {
+                return Some(Ok(pair));
+            }
+
+            // No matches remain for this LHS value
+            let left = self.lhs_iter.next()?;
+
+            // Searched without get_range, whose debug checks could panic on a failed comparison
+            self.rhs_range = if self.rhs.is_sorted() {
+                self.rhs.get_bounds(left, &predicate)
+            } else {
+                (0, self.rhs.len())
+            };
+            self.current_left = Some(left);
+        }
+    }
+}
+
+#[test]
+fn test_try_inner_join() {
+    let left = ["2024-01-02", "2024-01-05", "2024-01-09"];
+    let right = [(20240102, "a"), (20240109, "b"), (20240109, "c")];
+    let predicate =
+        |l: &&str, r: &(u32, &str)| l.replace('-', "").parse::<u32>().map(|l| l.cmp(&r.0));
+
+    for rhs in [RHS::new_unsorted(&right), RHS::new_sorted(&right)] {
+        let joined = try_inner_join(&left, rhs, predicate)
+            .map(|pair| pair.map(|(l, r)| (*l, r.1)))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            joined,
+            Ok(vec![
+                ("2024-01-02", "a"),
+                ("2024-01-09", "b"),
+                ("2024-01-09", "c")
+            ])
+        );
+    }
+
+    // The error is yielded once, ending the join
+    let left = ["2024-01-02", "January 9", "2024-01-09"];
+    for rhs in [RHS::new_unsorted(&right), RHS::new_sorted(&right)] {
+        let mut joined = try_inner_join(&left, rhs, predicate);
+        assert!(matches!(joined.next(), Some(Ok((_, (20240102, "a"))))));
+        assert!(matches!(joined.next(), Some(Err(_))));
+        assert!(joined.next().is_none());
+    }
+}
@@ -0,0 +1,232 @@
+use std::io::{self, BufRead, Write};
+
+/// Inner joins the lines of two sources that are sorted by key, writing each matching pair of
+/// lines to `out` as `left`, `delimiter`, `right`, and a newline.
+///
+/// This is a merge join: both sources are read once, line by line, so neither needs to fit in
+/// memory. Only the right lines sharing the current key are buffered, to pair with every left line
+/// of that key. `key` parses the join key from a line of either source, and the first
+/// `skip_header` lines of each source are skipped without being parsed.
+///
+/// Both sources are assumed to be sorted in ascending key order. If `verify_sorted` is `true`,
+/// this assumption is checked and a line out of order is reported as an
+/// [InvalidData](io::ErrorKind::InvalidData) error; otherwise, matches may be silently missed.
+/// I/O errors from either source or from `out` are returned as they occur. On success, returns
+/// the number of lines written.
+pub fn file_join<A, B, W, K, F>(
+    left: A,
+    right: B,
+    key: F,
+    skip_header: usize,
+    verify_sorted: bool,
+    delimiter: &str,
+    out: &mut W,
+) -> io::Result<usize>
+where
+    A: BufRead,
+    B: BufRead,
+    W: Write,
+    K: Ord + Clone,
+    F: Fn(&str) -> K,
+{
+    let mut lefts = SortedLines::new(left, &key, skip_header, verify_sorted, "left");
+    let mut rights = SortedLines::new(right, &key, skip_header, verify_sorted, "right");
+
+    // The right lines matching `group_key`
+    let mut group_key = None;
+    let mut group = Vec::new();
+    let mut next_right = rights.next()?;
+    let mut written = 0;
+
+    while let Some((lk, l)) = lefts.next()? {
+        if group_key.as_ref() != Some(&lk) {
+            group.clear();
+
+            while let Some((rk, r)) = next_right.take() {
+                if rk > lk {
+                    next_right = Some((rk, r));
+                    break;
+                }
+                if rk == lk {
+                    group.push(r);
+                }
+                next_right = rights.next()?;
+            }
+
+            group_key = Some(lk);
+        }
+
+        for r in &group {
+            writeln!(out, "{l}{delimiter}{r}")?;
+            written += 1;
+        }
+    }
+
+    Ok(written)
+}
+
+/// Reads keyed lines from a source, optionally checking that the keys are in order.
+struct SortedLines<'k, S, F, K> {
+    lines: io::Lines<S>,
+    key: &'k F,
+
+    /// The number of header lines still to be skipped
+    skip: usize,
+    verify_sorted: bool,
+
+    /// The key of the last line read, if it's being verified
+    last_key: Option<K>,
+
+    /// The number of lines read, including any header
+    line_number: usize,
+
+    /// Which source this is, for error messages
+    side: &'static str,
+}
+
+impl<'k, S, F, K> SortedLines<'k, S, F, K>
+where
+    S: BufRead,
+    F: Fn(&str) -> K,
+    K: Ord + Clone,
+{
+    fn new(source: S, key: &'k F, skip: usize, verify_sorted: bool, side: &'static str) -> Self {
+        Self {
+            lines: source.lines(),
+            key,
+            skip,
+            verify_sorted,
+            last_key: None,
+            line_number: 0,
+            side,
+        }
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        let line = self.lines.next().transpose()?;
+        if line.is_some() {
+            self.line_number += 1;
+        }
+        Ok(line)
+    }
+
+    fn next(&mut self) -> io::Result<Option<(K, String)>> {
+        while self.skip > 0 {
+            self.skip -= 1;
+            if self.next_line()?.is_none() {
+                return Ok(None);
+            }
+        }
+
+        let line = match self.next_line()? {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+        let key = (self.key)(&line);
+
+        if self.verify_sorted {
+            if matches!(&self.last_key, Some(last) if *last > key) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{} source is not sorted by key at line {}",
+                        self.side, self.line_number
+                    ),
+                ));
+            }
+            self.last_key = Some(key.clone());
+        }
+
+        Ok(Some((key, line)))
+    }
+}
+
+#[test]
+fn test_file_join() {
+    use std::io::Cursor;
+
+    let customers = "id,name\n1,ann\n2,bob\n4,di\n";
+    let orders = "id,item\n1,apple\n1,avocado\n3,cherry\n4,date\n";
+    let id = |line: &str| line.split(',').next().unwrap().parse::<u32>().unwrap();
+
+    let mut out = Vec::new();
+    let written = file_join(
+        Cursor::new(customers),
+        Cursor::new(orders),
+        id,
+        1,
+        true,
+        "|",
+        &mut out,
+    )
+    .unwrap();
+
+    assert_eq!(written, 3);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "1,ann|1,apple\n1,ann|1,avocado\n4,di|4,date\n"
+    );
+}
+
+#[test]
+fn test_file_join_duplicate_left_keys() {
+    use std::io::Cursor;
+
+    let left = "a 1\na 2\nb 3\n";
+    let right = "a x\na y\nc z\n";
+    let key = |line: &str| line.split(' ').next().unwrap().to_string();
+
+    let mut out = Vec::new();
+    let written = file_join(
+        Cursor::new(left),
+        Cursor::new(right),
+        key,
+        0,
+        true,
+        "\t",
+        &mut out,
+    )
+    .unwrap();
+
+    assert_eq!(written, 4);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "a 1\ta x\na 1\ta y\na 2\ta x\na 2\ta y\n"
+    );
+}
+
+#[test]
+fn test_file_join_errors() {
+    use std::io::Cursor;
+
+    let key = |line: &str| line.to_string();
+
+    // An unsorted right source
+    let mut out = Vec::new();
+    let err = file_join(
+        Cursor::new("a\nb\nc\n"),
+        Cursor::new("a\nc\nb\n"),
+        key,
+        0,
+        true,
+        ",",
+        &mut out,
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("right source"));
+
+    // Invalid UTF-8 in the left source, as a read error
+    let mut out = Vec::new();
+    let err = file_join(
+        Cursor::new(&b"a\n\xff\n"[..]),
+        Cursor::new("a\n"),
+        key,
+        0,
+        false,
+        ",",
+        &mut out,
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
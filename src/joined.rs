@@ -1,11 +1,29 @@
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "std")]
+use crate::analysis::{CountedKeys, DuplicateKeys};
+use crate::cardinality::{Cardinality, JoinedValidated};
+use crate::collector::Collector;
+use crate::metrics::{JoinMetrics, JoinedInstrumented};
+use crate::rhs::SortError;
 use crate::RHS;
 
 /// A trait allowing the joining of a left-hand side (LHS) and a right-hand side ([RHS]) dataset.
 /// Results are yielded for pairs of values from LHS and RHS.
+///
+/// Joined references live as long as the data they borrow, `'a`, but the join iterators and
+/// [RHS] are covariant in `'a`. Results can therefore be passed wherever a shorter lifetime is
+/// expected, such as into a scoped callback taking `&'b R`, without any conversion.
+///
+/// This is implemented for anything that can be iterated over as `&L`, so
+/// `customers.inner_join(...)` works directly on a `Vec` or array of customers, borrowing it, as
+/// well as on `customers.iter()`.
 pub trait Joinable<'a, LIt, R, P, L> {
     /// Joins LHS and RHS, keeping only records from left that have one or more matches in right.
     ///
-    /// The specified predicate returns a [std::cmp::Ordering] comparing left and right records.
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
     ///
     /// Unlike [Joinable::inner_join], this function returns one `(&L, &R)` for every match; that is, if a
     /// record, `L` has multiple matches in RHS, it will be yielded multiple times.
@@ -20,14 +38,351 @@ pub trait Joinable<'a, LIt, R, P, L> {
         rhs: impl Into<RHS<'a, R>>,
         predicate: P,
     ) -> JoinedEachOuter<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS as [outer_join](Joinable::outer_join) does, but yields `sentinel` for
+    /// records from left that have no matches in right.
+    ///
+    /// This function returns `(&L, &R)` values, so that no `Option` needs to be unwrapped
+    /// downstream when there is a natural "null object" to stand in for a missing match.
+    fn outer_join_sentinel(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        sentinel: &'a R,
+    ) -> JoinedSentinel<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS, keeping every record from both sides.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns `(Some(&L), Some(&R))` for every match, `(Some(&L), None)` for each
+    /// record in left with no matches, and `(None, Some(&R))` for each record in right with no
+    /// matches. The LHS-driven results come first, in the same order as
+    /// [outer_join](Joinable::outer_join); the unmatched right records follow, in RHS order, once
+    /// LHS is exhausted. Which right records matched is tracked with one `bool` per RHS record.
+    fn full_outer_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedFullOuter<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS, keeping every record from right, whether or not it has matches in left.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records,
+    /// just as for [outer_join](Joinable::outer_join).
+    ///
+    /// This function returns `(Some(&L), &R)` for every match and `(None, &R)` for each record in
+    /// right with no matches. Results are in RHS order, with the matches of each right record in
+    /// LHS order.
+    ///
+    /// RHS drives this join, so LHS is buffered the first time the join is iterated, costing one
+    /// reference per left record. Each right record is then compared against every buffered left
+    /// record, regardless of whether RHS is sorted.
+    fn right_outer_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedRightOuter<'a, LIt, R, P, L>;
+
+    /// Joins LHS and a sorted RHS, yielding each record from left along with the right records
+    /// immediately below and above it, such as for interpolating between them.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns one `(&L, Option<(&R, &R)>)` per left record. When a left record
+    /// exactly matches a right record, that right record brackets itself; when a left record
+    /// sorts before the first or after the last right record, `None` is returned.
+    ///
+    /// # Panics
+    /// Panics if RHS is not [sorted](RHS::Sorted).
+    fn interpolation_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedInterpolated<'a, LIt, R, P>;
+
+    /// Joins LHS and a sorted RHS, matching each record from left with the last right record at
+    /// or before it, such as the configuration in effect at the time of a reading.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns one `(&L, Option<&R>)` per left record. Of the right records that
+    /// compare less than or equal to the left record, the last is picked; if there are none, `None`
+    /// is returned.
+    ///
+    /// # Panics
+    /// Panics if RHS is not [sorted](RHS::Sorted).
+    fn asof_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedAsOf<'a, LIt, R, P>;
+
+    /// Joins a sorted LHS and a sorted RHS, keeping only records from left that have one or more
+    /// matches in right.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Rather than searching RHS for each left record, this advances through LHS and RHS together,
+    /// so the whole join is O(n + m) plus the size of the output. Left records with equal keys are
+    /// each paired with every matching right record. LHS must be sorted consistently with the
+    /// predicate; in debug builds, a left record that sorts before a right record already passed
+    /// over causes a panic.
+    ///
+    /// # Panics
+    /// Panics if RHS is not [sorted](RHS::Sorted) or [sorted chunks](RHS::SortedChunks). A
+    /// [PartialSort](crate::PartialSort) is not accepted, since it is only sorted as far as
+    /// searches require.
+    fn merge_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedMerge<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// along with the neighbors of each match.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns one `(&L, &R, Option<&R>, Option<&R>)` for every match, where the
+    /// options are the right records immediately before and after the match in RHS (or `None` at
+    /// either end). This is most useful when RHS is [sorted](RHS::Sorted), so that neighbors give
+    /// local context around each match.
+    fn inner_join_with_neighbors(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedNeighbors<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS using a list of predicates in priority order, keeping only records from
+    /// left that have one or more matches in right.
+    ///
+    /// For each left record, every predicate is tried in turn and the matches from the first
+    /// predicate that produces any are yielded as `(&L, &R, usize)`, where the `usize` is the
+    /// index of that predicate. This is the usual strong-key-then-weak-key record linkage pattern.
+    ///
+    /// If RHS is [RHS::Sorted], it must be sorted consistently with every predicate.
+    fn inner_join_cascade<'p>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicates: &'p [P],
+    ) -> JoinedCascade<'a, 'p, LIt, R, P, L>;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right
+    /// according to any of a list of predicates.
+    ///
+    /// Each predicate returns a [core::cmp::Ordering] comparing left and right records, and a pair
+    /// matches if any of them returns [Equal](core::cmp::Ordering::Equal). This function returns
+    /// one `(&L, &R)` for every match; a right record matching on several predicates is still
+    /// yielded only once per left record.
+    ///
+    /// Because no single sort order is consistent with all predicates, RHS is always scanned
+    /// linearly, even if it is [sorted](RHS::Sorted).
+    fn inner_join_any<'p>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicates: &'p [P],
+    ) -> JoinedAny<'a, 'p, LIt, R, P, L>;
+
+    /// Joins LHS and RHS on a primary predicate, then picks the nearest of each left record's
+    /// matches according to a secondary predicate.
+    ///
+    /// Both predicates return a [core::cmp::Ordering] comparing left and right records. The
+    /// matches of `primary` are assumed to be in order according to `secondary`. From them, the
+    /// first that `secondary` finds [Equal](core::cmp::Ordering::Equal) is chosen; failing that,
+    /// the last match that sorts before the left record, or else the first match after it. This
+    /// function returns one `(&L, Option<&R>)` per left record, with `None` when `primary` has no
+    /// matches.
+    fn nearest_within_group_join<S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        primary: P,
+        secondary: S,
+    ) -> JoinedNearestWithinGroup<'a, LIt, R, P, S>
+    where
+        S: Fn(&L, &R) -> core::cmp::Ordering;
+
+    /// Joins LHS and RHS, grading how well each record from left matched.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// For each left record, the first exact match according to `predicate` is yielded as
+    /// [Match::Exact]. Failing that, every right record is scored with `fuzzy_score` and the
+    /// highest scoring one at or above `threshold` is yielded as [Match::Fuzzy] (ties go to the
+    /// earliest in RHS). Otherwise, [Match::None] is yielded. Fuzzy scoring is a linear scan of
+    /// RHS regardless of whether it is sorted.
+    fn graded_join<S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        fuzzy_score: S,
+        threshold: f64,
+    ) -> JoinedGraded<'a, LIt, R, P, S>
+    where
+        S: Fn(&L, &R) -> f64;
+
+    /// Joins LHS and RHS as [Joinable::inner_join] does, but yields each distinct match of a left
+    /// record only once.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Matches are distinct according to `eq`, not according to the join predicate: two right
+    /// records with the same join key but which `eq` finds unequal are both yielded. Of equal
+    /// matches, the first in RHS order is kept. Each match is compared against the distinct
+    /// matches already yielded for the same left record, so this suits records with a modest
+    /// number of matches.
+    fn dedup_join<E>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        eq: E,
+    ) -> JoinedDedup<'a, LIt, R, P, L, E>
+    where
+        E: Fn(&R, &R) -> bool;
+
+    /// Joins LHS and RHS as [Joinable::inner_join] does, yielding each match along with its
+    /// position in RHS.
+    ///
+    /// Each match is yielded as `(&L, usize, &R)`, where the `usize` is the index of the right
+    /// record in the slice RHS was created from, so matches can be correlated with a parallel
+    /// array without searching again. For a [partially sorted](RHS::PartiallySorted) RHS, this is
+    /// the index in the wrapped slice rather than in its sorted order; for chunked RHS, chunks are
+    /// numbered as if they were concatenated.
+    fn inner_join_indexed(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedIndexed<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// with each left record's matches grouped together.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This is the borrowing counterpart of
+    /// [inner_join_grouped](crate::JoinableGrouped::inner_join_grouped), returning a
+    /// `(&L, Vec<&R>)` for each record in left that has matches. Calling `inner_join_grouped`
+    /// on `.iter()` also works, but its predicate then takes `&&L`; this one takes `&L`, the same
+    /// as [Joinable::inner_join], so the same predicate serves both grouped and ungrouped joins.
+    fn inner_join_grouped_ref(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedGroupedRef<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS as [Joinable::inner_join] does, while recording any left keys that
+    /// appear more than once.
+    ///
+    /// Along with the join iterator, this returns a [DuplicateKeys] handle that is filled in as the
+    /// join is iterated. Duplicate left keys often indicate a data problem that silently
+    /// multiplies joined rows.
+    #[cfg(feature = "std")]
+    #[allow(clippy::type_complexity)]
+    fn inner_join_checked<K, F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        left_key: F,
+    ) -> (
+        JoinedEachInner<'a, CountedKeys<LIt, F, K>, R, P, L>,
+        DuplicateKeys<K>,
+    )
+    where
+        F: Fn(&&'a L) -> K,
+        K: core::hash::Hash + Eq + Clone;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// without enumerating the matches up front.
+    ///
+    /// Each left record is yielded once as `(&L, MatchHandle)`. The [MatchHandle] is an iterator
+    /// over that record's matches which is only advanced on demand, so matches for left records
+    /// that end up being discarded are never enumerated. For [sorted](RHS::Sorted) RHS, the handle
+    /// is simply the matching range; otherwise, it filters the rest of RHS as it is iterated.
+    fn inner_join_deferred(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedDeferred<'a, LIt, R, P>
+    where
+        P: Clone;
+
+    /// Joins LHS and RHS as [Joinable::inner_join] does, while checking that matches have the
+    /// expected [Cardinality].
+    ///
+    /// Each match is yielded as `Ok((&L, &R))` until a left record matches more right records,
+    /// or a right record matches more left records, than `cardinality` allows. That violation is
+    /// then yielded as an `Err`, after which iteration stops. Collecting into a
+    /// `Result<Vec<_>, _>` gives either every match or the first violation.
+    fn inner_join_validated(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        cardinality: Cardinality,
+    ) -> JoinedValidated<'a, LIt, R, P, L>;
+
+    /// Joins LHS and a sorted RHS as [Joinable::inner_join] does, while checking the result of
+    /// each binary search against the predicate.
+    ///
+    /// A predicate that doesn't agree with the order of RHS silently produces missing matches. In
+    /// this mode, each match is yielded as `Ok((&L, &R))` until a search result is found to be
+    /// inconsistent (its endpoints don't compare [Equal](core::cmp::Ordering::Equal) or its
+    /// neighbors do), at which point a [SortError] is yielded and iteration stops. The extra
+    /// comparisons make this slower than [Joinable::inner_join]; an unsorted RHS is not checked.
+    fn inner_join_checked_sorted(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedCheckedSorted<'a, LIt, R, P, L>;
+
+    /// Joins LHS and RHS as [Joinable::inner_join] does, while counting the work done.
+    ///
+    /// Along with the join iterator, this returns a [JoinMetrics] handle that is filled in as the
+    /// join is iterated, such as for checking that a sorted RHS is actually being binary searched.
+    /// [Joinable::inner_join] itself is not instrumented, so has no such overhead.
+    fn instrumented_inner_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (JoinedInstrumented<'a, LIt, R, P, L>, JoinMetrics);
+}
+
+/// How a record from left matched in a [graded_join](Joinable::graded_join).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Match<'a, R> {
+    /// The right record compared equal according to the join predicate.
+    Exact(&'a R),
+
+    /// No exact match was found; this is the best fuzzy match along with its score.
+    Fuzzy(&'a R, f64),
+
+    /// Neither an exact nor a good enough fuzzy match was found.
+    None,
+}
+
+/// The lazily-evaluated matches for a single left record from an
+/// [inner_join_deferred](Joinable::inner_join_deferred), yielding `&R` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct MatchHandle<'a, L, R, P> {
+    left: &'a L,
+    rhs: RHS<'a, R>,
+
+    /// The remaining range of RHS that may match
+    rhs_range: (usize, usize),
+    predicate: P,
 }
 
-impl<'a, LIt, R, P, L> Joinable<'a, LIt, R, P, L> for LIt
+impl<'a, L, R, P> Iterator for MatchHandle<'a, L, R, P>
 where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = &'a R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rhs
+            .next_match(self.left, &self.predicate, &mut self.rhs_range)
+            .map(|(_, r)| r)
+    }
+}
+
+impl<'a, I, LIt, R, P, L> Joinable<'a, LIt, R, P, L> for I
+where
+    I: IntoIterator<Item = &'a L, IntoIter = LIt>,
     LIt: Iterator<Item = &'a L>,
     L: 'a,
     R: 'a,
-    P: Fn(&L, &R) -> std::cmp::Ordering,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
 {
     fn inner_join(
         self,
@@ -35,7 +390,7 @@ where
         predicate: P,
     ) -> JoinedEachInner<'a, LIt, R, P, L> {
         JoinedEachInner {
-            lhs_iter: self,
+            lhs_iter: self.into_iter(),
             rhs: rhs.into(),
             rhs_range: (1, 0),
             predicate,
@@ -49,157 +404,1451 @@ where
         predicate: P,
     ) -> JoinedEachOuter<'a, LIt, R, P, L> {
         JoinedEachOuter {
-            lhs_iter: self,
+            lhs_iter: self.into_iter(),
             rhs: rhs.into(),
             current_left: None,
             rhs_range: (1, 0),
             predicate,
         }
     }
-}
 
-/// The intermediate result of a semi- or anti-join that will yield `(L, &R)` values.
-pub struct JoinedEachInner<'a, LIt, R, P, L> {
-    /// User-supplied predicate that accepts (&L, &R) and returns an Ordering
-    predicate: P,
+    fn outer_join_sentinel(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        sentinel: &'a R,
+    ) -> JoinedSentinel<'a, LIt, R, P, L> {
+        JoinedSentinel {
+            outer: self.outer_join(rhs, predicate),
+            sentinel,
+        }
+    }
 
-    /// Iterator of LHS values which are necessarily borrowed.
-    lhs_iter: LIt,
+    fn full_outer_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedFullOuter<'a, LIt, R, P, L> {
+        let rhs = rhs.into();
+        let rights_matched = vec![false; rhs.len()];
 
-    /// The current LHS value. If None, one will be taken from [lhs_iter]. When no more matches are
-    /// found in RHS, this will be set to None again.
-    current_left: Option<&'a L>,
+        JoinedFullOuter {
+            lhs_iter: self.into_iter(),
+            current_left: None,
+            rhs,
+            rhs_range: (1, 0),
+            predicate,
+            rights_matched,
+            lhs_done: false,
+            next_right: 0,
+        }
+    }
 
-    /// The RHS values to search.
-    rhs: RHS<'a, R>,
+    fn right_outer_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedRightOuter<'a, LIt, R, P, L> {
+        JoinedRightOuter {
+            lhs_iter: Some(self.into_iter()),
+            lefts: Vec::new(),
+            rhs: rhs.into(),
+            predicate,
+            next_right: 0,
+            current_right: None,
+        }
+    }
 
-    /// The range in RHS where values will be taken.
+    fn asof_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedAsOf<'a, LIt, R, P> {
+        let rhs = rhs.into();
+        assert!(rhs.is_sorted(), "asof_join requires a sorted RHS");
 
-    /// If RHS is Unsorted, the range starts off as 0..rhs.len(). If sorted, the values are set
-    /// according to a binary search. Upon each iteration, the lower value is updated to restrict
-    /// subsequent search space.
-    rhs_range: (usize, usize),
-}
+        JoinedAsOf {
+            lhs_iter: self.into_iter(),
+            rhs,
+            predicate,
+        }
+    }
 
-impl<'a, LIt, R, P, L> Iterator for JoinedEachInner<'a, LIt, R, P, L>
-where
-    LIt: Iterator<Item = &'a L>,
-    L: 'a,
-    R: 'a,
-    P: Fn(&L, &R) -> std::cmp::Ordering,
-    R: std::fmt::Debug,
-{
-    type Item = (&'a L, &'a R);
+    fn merge_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedMerge<'a, LIt, R, P, L> {
+        let rhs = rhs.into();
+        assert!(
+            matches!(rhs, RHS::Sorted(_) | RHS::SortedChunks(_)),
+            "merge_join requires a sorted RHS"
+        );
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let left: &'a L = if let Some(l) = self.current_left {
-                l
-            } else if let Some(l) = self.lhs_iter.next() {
-                self.rhs_range = self.rhs.get_range(l, &self.predicate);
-                self.current_left = Some(l);
-                l
-            } else {
-                // If LHS has no more, then we stop iteration altogether
-                self.rhs_range = (1, 0);
-                self.current_left = None;
-                return None;
-            };
+        JoinedMerge {
+            lhs_iter: self.into_iter(),
+            current_left: None,
+            rhs,
+            predicate,
+            run_start: 0,
+            next_right: 0,
+        }
+    }
 
-            match self.rhs {
-                RHS::Unsorted(u) => {
-                    for i in self.rhs_range.0..self.rhs_range.1 {
-                        if (self.predicate)(left, &u[i]).is_eq() {
-                            self.rhs_range.0 = i + 1;
-                            return Some((left, &u[i]));
-                        }
-                    }
+    fn interpolation_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedInterpolated<'a, LIt, R, P> {
+        let rhs = rhs.into();
+        assert!(rhs.is_sorted(), "interpolation_join requires a sorted RHS");
 
-                    // No matches remain for this LHS value
-                    self.current_left.take();
-                }
-                RHS::Sorted(s) => {
-                    if self.rhs_range.0 < self.rhs_range.1 {
-                        // we can use one of these values
-                        self.rhs_range.0 += 1;
-                        return Some((left, &s[self.rhs_range.0 - 1]));
-                    } else {
-                        self.current_left.take();
-                    }
-                }
-            }
+        JoinedInterpolated {
+            lhs_iter: self.into_iter(),
+            rhs,
+            predicate,
         }
     }
-}
 
-pub struct JoinedEachOuter<'a, LIt, R, P, L> {
-    lhs_iter: LIt,
-    current_left: Option<&'a L>,
-    rhs: RHS<'a, R>,
-    rhs_range: (usize, usize),
-    predicate: P,
-}
+    fn inner_join_with_neighbors(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedNeighbors<'a, LIt, R, P, L> {
+        JoinedNeighbors {
+            lhs_iter: self.into_iter(),
+            current_left: None,
+            rhs: rhs.into(),
+            rhs_range: (1, 0),
+            predicate,
+        }
+    }
 
-impl<'a, LIt, R, P, L> Iterator for JoinedEachOuter<'a, LIt, R, P, L>
-where
-    LIt: Iterator<Item = &'a L>,
-    L: 'a,
-    R: 'a,
-    P: Fn(&L, &R) -> std::cmp::Ordering,
-    R: std::fmt::Debug,
-{
-    type Item = (&'a L, Option<&'a R>);
+    fn inner_join_cascade<'p>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicates: &'p [P],
+    ) -> JoinedCascade<'a, 'p, LIt, R, P, L> {
+        JoinedCascade {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicates,
+            current_left: None,
+            rhs_range: (1, 0),
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let (left, is_first) = if let Some(l) = self.current_left {
-                (l, false)
-            } else if let Some(l) = self.lhs_iter.next() {
-                self.rhs_range = self.rhs.get_range(l, &self.predicate);
-                self.current_left = Some(l);
-                (l, true)
-            } else {
-                // If LHS has no more, then we stop iteration altogether
-                self.rhs_range = (1, 0);
-                self.current_left = None;
-                return None;
-            };
+    fn inner_join_any<'p>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicates: &'p [P],
+    ) -> JoinedAny<'a, 'p, LIt, R, P, L> {
+        JoinedAny {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicates,
+            current_left: None,
+            rhs_pos: 0,
+        }
+    }
+
+    fn nearest_within_group_join<S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        primary: P,
+        secondary: S,
+    ) -> JoinedNearestWithinGroup<'a, LIt, R, P, S>
+    where
+        S: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        JoinedNearestWithinGroup {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            primary,
+            secondary,
+        }
+    }
+
+    fn graded_join<S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        fuzzy_score: S,
+        threshold: f64,
+    ) -> JoinedGraded<'a, LIt, R, P, S>
+    where
+        S: Fn(&L, &R) -> f64,
+    {
+        JoinedGraded {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            fuzzy_score,
+            threshold,
+        }
+    }
+
+    fn dedup_join<E>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        eq: E,
+    ) -> JoinedDedup<'a, LIt, R, P, L, E>
+    where
+        E: Fn(&R, &R) -> bool,
+    {
+        JoinedDedup {
+            lhs_iter: self.into_iter(),
+            current_left: None,
+            rhs: rhs.into(),
+            rhs_range: (1, 0),
+            predicate,
+            eq,
+            seen: Vec::new(),
+        }
+    }
+
+    fn inner_join_indexed(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedIndexed<'a, LIt, R, P, L> {
+        JoinedIndexed {
+            lhs_iter: self.into_iter(),
+            current_left: None,
+            rhs: rhs.into(),
+            rhs_range: (1, 0),
+            predicate,
+        }
+    }
+
+    fn inner_join_grouped_ref(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedGroupedRef<'a, LIt, R, P> {
+        JoinedGroupedRef {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::type_complexity)]
+    fn inner_join_checked<K, F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        left_key: F,
+    ) -> (
+        JoinedEachInner<'a, CountedKeys<LIt, F, K>, R, P, L>,
+        DuplicateKeys<K>,
+    )
+    where
+        F: Fn(&&'a L) -> K,
+        K: core::hash::Hash + Eq + Clone,
+    {
+        let (lhs, duplicates) = CountedKeys::new(self.into_iter(), left_key);
+        (lhs.inner_join(rhs, predicate), duplicates)
+    }
+
+    fn inner_join_deferred(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedDeferred<'a, LIt, R, P>
+    where
+        P: Clone,
+    {
+        JoinedDeferred {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+        }
+    }
+
+    fn inner_join_validated(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        cardinality: Cardinality,
+    ) -> JoinedValidated<'a, LIt, R, P, L> {
+        JoinedValidated::new(self.into_iter(), rhs.into(), predicate, cardinality)
+    }
+
+    fn inner_join_checked_sorted(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedCheckedSorted<'a, LIt, R, P, L> {
+        JoinedCheckedSorted {
+            lhs_iter: self.into_iter(),
+            current_left: None,
+            rhs: rhs.into(),
+            rhs_range: (1, 0),
+            predicate,
+            failed: false,
+        }
+    }
+
+    fn instrumented_inner_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (JoinedInstrumented<'a, LIt, R, P, L>, JoinMetrics) {
+        JoinedInstrumented::new(self.into_iter(), rhs.into(), predicate)
+    }
+}
+
+/// The intermediate result of a semi- or anti-join that will yield `(L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedEachInner<'a, LIt, R, P, L> {
+    /// User-supplied predicate that accepts (&L, &R) and returns an Ordering
+    predicate: P,
+
+    /// Iterator of LHS values which are necessarily borrowed.
+    pub(crate) lhs_iter: LIt,
+
+    /// The current LHS value. If None, one will be taken from [lhs_iter]. When no more matches are
+    /// found in RHS, this will be set to None again.
+    current_left: Option<&'a L>,
+
+    /// The RHS values to search.
+    rhs: RHS<'a, R>,
+
+    /// The range in RHS where values will be taken.
+
+    /// If RHS is Unsorted, the range starts off as 0..rhs.len(). If sorted, the values are set
+    /// according to a binary search. Upon each iteration, the lower value is updated to restrict
+    /// subsequent search space.
+    rhs_range: (usize, usize),
+}
+
+impl<'a, LIt, R, P, L> JoinedEachInner<'a, LIt, R, P, L>
+where
+    Self: Iterator,
+{
+    /// Clears `out` and fills it with every remaining result, reusing its existing allocation.
+    ///
+    /// This is useful when the same join is run repeatedly, such as once per frame.
+    pub fn collect_into(self, out: &mut Vec<<Self as Iterator>::Item>) {
+        out.clear();
+        out.extend(self);
+    }
+
+    /// Pushes every remaining result into `collector`, which is returned. Pass `&mut collector`
+    /// to keep ownership of it.
+    pub fn collect_with<C>(self, collector: C) -> C
+    where
+        C: Collector<<Self as Iterator>::Item>,
+    {
+        crate::collector::collect_with(self, collector)
+    }
+}
+
+impl<'a, LIt, R, P, L> JoinedEachInner<'a, LIt, R, P, L> {
+    /// Drops records from left for which `skip` returns `true` before RHS is searched for them.
+    ///
+    /// This is like filtering LHS before joining, but keeps the join chain fluent and takes `&L`
+    /// rather than `&&L`.
+    pub fn skip_left_if<F>(self, skip: F) -> JoinedEachInner<'a, SkipLeft<LIt, F>, R, P, L>
+    where
+        F: Fn(&L) -> bool,
+    {
+        JoinedEachInner {
+            predicate: self.predicate,
+            lhs_iter: SkipLeft {
+                lhs_iter: self.lhs_iter,
+                skip,
+            },
+            current_left: self.current_left,
+            rhs: self.rhs,
+            rhs_range: self.rhs_range,
+        }
+    }
+
+    /// Limits the join to `budget` predicate calls, guarding against accidentally quadratic
+    /// joins of large inputs.
+    ///
+    /// Once the budget is spent, iteration ends, even if more matches remain. Use
+    /// [JoinedBudgeted::budget_exhausted] to tell whether the join was cut short.
+    pub fn comparison_budget(self, budget: usize) -> JoinedBudgeted<'a, LIt, R, P, L> {
+        JoinedBudgeted {
+            inner: self,
+            remaining: Cell::new(budget),
+            exhausted: Cell::new(false),
+        }
+    }
+
+    /// Reuses the matches of a left record for the records after it with the same key, rather
+    /// than searching RHS again, such as for a sorted LHS joined against an unsorted RHS.
+    ///
+    /// A left record is known to have the same key as the one before it when it matches that
+    /// record's first match, which takes one predicate call; a run of `k` equal left records then
+    /// costs one search of RHS rather than `k`. This relies on the predicate's
+    /// [Equal](core::cmp::Ordering::Equal) being consistent, as for any key comparison. Left records
+    /// with no matches are always searched for.
+    pub fn reuse_repeated_left(self) -> JoinedReusingRepeats<'a, LIt, R, P, L> {
+        JoinedReusingRepeats {
+            inner: self,
+            matches: Vec::new(),
+            replay: None,
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedEachInner<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left: &'a L = if let Some(l) = self.current_left {
+                l
+            } else if let Some(l) = self.lhs_iter.next() {
+                self.rhs_range = self.rhs.get_range(l, &self.predicate);
+                self.current_left = Some(l);
+                l
+            } else {
+                // If LHS has no more, then we stop iteration altogether
+                self.rhs_range = (1, 0);
+                self.current_left = None;
+                return None;
+            };
+
+            if let Some((_, r)) = self
+                .rhs
+                .next_match(left, &self.predicate, &mut self.rhs_range)
+            {
+                return Some((left, r));
+            }
+
+            // No matches remain for this LHS value
+            self.current_left.take();
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> FusedIterator for JoinedEachInner<'a, LIt, R, P, L>
+where
+    LIt: FusedIterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+}
+
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedEachOuter<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> JoinedEachOuter<'a, LIt, R, P, L>
+where
+    Self: Iterator,
+{
+    /// Clears `out` and fills it with every remaining result, reusing its existing allocation.
+    pub fn collect_into(self, out: &mut Vec<<Self as Iterator>::Item>) {
+        out.clear();
+        out.extend(self);
+    }
+
+    /// Pushes every remaining result into `collector`, which is returned. Pass `&mut collector`
+    /// to keep ownership of it.
+    pub fn collect_with<C>(self, collector: C) -> C
+    where
+        C: Collector<<Self as Iterator>::Item>,
+    {
+        crate::collector::collect_with(self, collector)
+    }
+}
+
+impl<'a, LIt, R, P, L> JoinedEachOuter<'a, LIt, R, P, L> {
+    /// Drops records from left for which `skip` returns `true` before RHS is searched for them.
+    /// Skipped records are not yielded at all, not even with `None`.
+    pub fn skip_left_if<F>(self, skip: F) -> JoinedEachOuter<'a, SkipLeft<LIt, F>, R, P, L>
+    where
+        F: Fn(&L) -> bool,
+    {
+        JoinedEachOuter {
+            lhs_iter: SkipLeft {
+                lhs_iter: self.lhs_iter,
+                skip,
+            },
+            current_left: self.current_left,
+            rhs: self.rhs,
+            rhs_range: self.rhs_range,
+            predicate: self.predicate,
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedEachOuter<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (left, is_first) = if let Some(l) = self.current_left {
+                (l, false)
+            } else if let Some(l) = self.lhs_iter.next() {
+                self.rhs_range = self.rhs.get_range(l, &self.predicate);
+                self.current_left = Some(l);
+                (l, true)
+            } else {
+                // If LHS has no more, then we stop iteration altogether
+                self.rhs_range = (1, 0);
+                self.current_left = None;
+                return None;
+            };
+
+            if let Some((_, r)) = self
+                .rhs
+                .next_match(left, &self.predicate, &mut self.rhs_range)
+            {
+                return Some((left, Some(r)));
+            } else if is_first {
+                // We pulled the LHS value without any return; indicate no RHS with a None
+                return Some((left, None));
+            } else {
+                // No matches remain for this LHS value
+                self.current_left.take();
+            }
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> FusedIterator for JoinedEachOuter<'a, LIt, R, P, L>
+where
+    LIt: FusedIterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+}
+
+/// The intermediate result of a [full outer join](Joinable::full_outer_join) that will yield
+/// `(Option<&L>, Option<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedFullOuter<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+
+    /// The current LHS value and whether it has matched yet
+    current_left: Option<(&'a L, bool)>,
+
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+
+    /// Which RHS values have matched, by their original position
+    rights_matched: Vec<bool>,
+
+    /// Set once LHS is exhausted, after which unmatched RHS values are yielded
+    lhs_done: bool,
+
+    /// The original position of the next RHS value to check once LHS is exhausted
+    next_right: usize,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedFullOuter<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (Option<&'a L>, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.lhs_done {
+            if let Some((left, matched)) = self.current_left.as_mut() {
+                if let Some((i, r)) =
+                    self.rhs
+                        .next_match(*left, &self.predicate, &mut self.rhs_range)
+                {
+                    *matched = true;
+                    self.rights_matched[self.rhs.original_index(i)] = true;
+                    return Some((Some(*left), Some(r)));
+                }
+
+                // No matches remain for this LHS value
+                let (left, matched) = self.current_left.take().unwrap();
+                if !matched {
+                    return Some((Some(left), None));
+                }
+            }
+
+            match self.lhs_iter.next() {
+                Some(left) => {
+                    self.rhs_range = self.rhs.get_range(left, &self.predicate);
+                    self.current_left = Some((left, false));
+                }
+                None => self.lhs_done = true,
+            }
+        }
+
+        while self.next_right < self.rights_matched.len() {
+            let i = self.next_right;
+            self.next_right += 1;
+
+            if !self.rights_matched[i] {
+                return Some((None, self.rhs.get_original(i)));
+            }
+        }
+
+        None
+    }
+}
+
+/// The intermediate result of a [right outer join](Joinable::right_outer_join) that will yield
+/// `(Option<&L>, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedRightOuter<'a, LIt, R, P, L> {
+    /// LHS, until it is buffered into `lefts`
+    lhs_iter: Option<LIt>,
+    lefts: Vec<&'a L>,
+
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// The index of the next RHS value to search for
+    next_right: usize,
+
+    /// The current RHS value, the index of the next LHS value to compare it with, and whether it
+    /// has matched yet
+    current_right: Option<(&'a R, usize, bool)>,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedRightOuter<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (Option<&'a L>, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(lhs_iter) = self.lhs_iter.take() {
+            self.lefts.extend(lhs_iter);
+        }
+
+        loop {
+            if let Some((right, next_left, matched)) = self.current_right.as_mut() {
+                while let Some(&left) = self.lefts.get(*next_left) {
+                    *next_left += 1;
+
+                    if (self.predicate)(left, right).is_eq() {
+                        *matched = true;
+                        return Some((Some(left), *right));
+                    }
+                }
+
+                // No matches remain for this RHS value
+                let (right, _, matched) = self.current_right.take().unwrap();
+                if !matched {
+                    return Some((None, right));
+                }
+            }
+
+            let right = self.rhs.get(self.next_right)?;
+            self.next_right += 1;
+            self.current_right = Some((right, 0, false));
+        }
+    }
+}
+
+/// The intermediate result of an [outer join with a sentinel](Joinable::outer_join_sentinel)
+/// that will yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedSentinel<'a, LIt, R, P, L> {
+    outer: JoinedEachOuter<'a, LIt, R, P, L>,
+
+    /// Yielded in place of a match for unmatched LHS values
+    sentinel: &'a R,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedSentinel<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (left, right) = self.outer.next()?;
+        Some((left, right.unwrap_or(self.sentinel)))
+    }
+}
+
+/// The intermediate result of an inner join with a
+/// [comparison budget](JoinedEachInner::comparison_budget) that will yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedBudgeted<'a, LIt, R, P, L> {
+    inner: JoinedEachInner<'a, LIt, R, P, L>,
+
+    /// The number of predicate calls that may still be made
+    remaining: Cell<usize>,
+
+    /// Set once a predicate call was needed beyond the budget
+    exhausted: Cell<bool>,
+}
+
+impl<'a, LIt, R, P, L> JoinedBudgeted<'a, LIt, R, P, L> {
+    /// Whether iteration ended because the comparison budget was spent.
+    pub fn budget_exhausted(&self) -> bool {
+        self.exhausted.get()
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedBudgeted<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (remaining, exhausted) = (&self.remaining, &self.exhausted);
+        let inner = &mut self.inner;
+        let predicate = |l: &L, r: &R| match remaining.get() {
+            0 => {
+                // The result is discarded once the current search returns
+                exhausted.set(true);
+                core::cmp::Ordering::Less
+            }
+            n => {
+                remaining.set(n - 1);
+                (inner.predicate)(l, r)
+            }
+        };
+
+        loop {
+            if exhausted.get() {
+                return None;
+            }
+
+            if let Some(left) = inner.current_left {
+                let found = inner.rhs.next_match(left, &predicate, &mut inner.rhs_range);
+
+                match found {
+                    _ if exhausted.get() => return None,
+                    Some((_, r)) => return Some((left, r)),
+                    // No matches remain for this LHS value
+                    None => inner.current_left = None,
+                }
+            }
+
+            let left = inner.lhs_iter.next()?;
+
+            // Searched without get_range, whose debug checks would spend the budget and could panic
+            inner.rhs_range = if inner.rhs.is_sorted() {
+                inner.rhs.get_bounds(left, &predicate)
+            } else {
+                (0, inner.rhs.len())
+            };
+            inner.current_left = Some(left);
+        }
+    }
+}
+
+/// The intermediate result of an inner join that
+/// [reuses the matches of repeated left records](JoinedEachInner::reuse_repeated_left) that will
+/// yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedReusingRepeats<'a, LIt, R, P, L> {
+    inner: JoinedEachInner<'a, LIt, R, P, L>,
+
+    /// The indices in RHS of the matches of the current key, found so far
+    matches: Vec<usize>,
+
+    /// While reusing `matches` for a repeated key, the position of the next one to yield
+    replay: Option<usize>,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedReusingRepeats<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.inner;
+
+        loop {
+            if let Some(left) = inner.current_left {
+                let found = match self.replay.as_mut() {
+                    Some(i) => {
+                        let found = self.matches.get(*i).and_then(|&j| inner.rhs.get(j));
+                        *i += 1;
+                        found
+                    }
+                    None => inner
+                        .rhs
+                        .next_match(left, &inner.predicate, &mut inner.rhs_range)
+                        .map(|(j, r)| {
+                            self.matches.push(j);
+                            r
+                        }),
+                };
+
+                if let Some(r) = found {
+                    return Some((left, r));
+                }
+
+                // No matches remain for this LHS value
+                inner.current_left = None;
+            }
+
+            let left = inner.lhs_iter.next()?;
+            let repeated = self
+                .matches
+                .first()
+                .and_then(|&j| inner.rhs.get(j))
+                .is_some_and(|r| (inner.predicate)(left, r) == core::cmp::Ordering::Equal);
+
+            if repeated {
+                self.replay = Some(0);
+            } else {
+                self.matches.clear();
+                self.replay = None;
+                inner.rhs_range = inner.rhs.get_range(left, &inner.predicate);
+            }
+            inner.current_left = Some(left);
+        }
+    }
+}
+
+/// Wraps a left-hand iterator, dropping the values for which `skip` returns `true`.
+pub struct SkipLeft<LIt, F> {
+    lhs_iter: LIt,
+    skip: F,
+}
+
+impl<'a, LIt, F, L> Iterator for SkipLeft<LIt, F>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    F: Fn(&L) -> bool,
+{
+    type Item = &'a L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let skip = &self.skip;
+        self.lhs_iter.find(|l| !skip(l))
+    }
+}
+
+/// The intermediate result of a checked inner join that will yield `Result<(&L, &R), SortError>`
+/// values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedCheckedSorted<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+
+    /// Set once an error has been yielded, after which iteration stops
+    failed: bool,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedCheckedSorted<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = Result<(&'a L, &'a R), SortError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        loop {
+            if let Some(left) = self.current_left {
+                if let Some((_, r)) =
+                    self.rhs
+                        .next_match(left, &self.predicate, &mut self.rhs_range)
+                {
+                    return Some(Ok((left, r)));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+
+            // Searched without get_range, which would panic in debug builds instead
+            if self.rhs.is_sorted() {
+                self.rhs_range = self.rhs.get_bounds(left, &self.predicate);
+                if let Err(e) = self.rhs.check_bounds(left, &self.predicate, self.rhs_range) {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            } else {
+                self.rhs_range = self.rhs.get_range(left, &self.predicate);
+            }
+
+            self.current_left = Some(left);
+        }
+    }
+}
+
+/// The intermediate result of an interpolation join that will yield `(&L, Option<(&R, &R)>)`
+/// values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedInterpolated<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedInterpolated<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, Option<(&'a R, &'a R)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+
+        let (lower, upper) = self.rhs.get_bounds(left, &self.predicate);
+        let bracket = if lower < upper {
+            // An exact match brackets itself
+            self.rhs.get(lower).map(|r| (r, r))
+        } else if lower == 0 {
+            None
+        } else {
+            // `lower` is the insertion point, so its neighbors bracket the left value
+            self.rhs.get(lower - 1).zip(self.rhs.get(lower))
+        };
+
+        Some((left, bracket))
+    }
+}
+
+/// The intermediate result of an [as-of join](Joinable::asof_join) that will yield
+/// `(&L, Option<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedAsOf<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedAsOf<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+
+        // The insertion point after every value at or before the left value; step back one
+        let (_, upper) = self.rhs.get_bounds(left, &self.predicate);
+        let predecessor = upper.checked_sub(1).and_then(|i| self.rhs.get(i));
+
+        Some((left, predecessor))
+    }
+}
+
+/// The intermediate result of a [merge join](Joinable::merge_join) that will yield `(&L, &R)`
+/// values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedMerge<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// The index of the first RHS value not before the current LHS value. Every RHS value before
+    /// it sorts before all remaining LHS values.
+    run_start: usize,
+
+    /// The index of the next RHS value to check against the current LHS value
+    next_right: usize,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedMerge<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use core::cmp::Ordering;
+
+        loop {
+            if let Some(left) = self.current_left {
+                if let Some(r) = self.rhs.get(self.next_right) {
+                    if (self.predicate)(left, r) == Ordering::Equal {
+                        self.next_right += 1;
+                        return Some((left, r));
+                    }
+                }
+
+                // The run of matches for this LHS value has ended
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+            debug_assert!(
+                self.run_start == 0
+                    || self
+                        .rhs
+                        .get(self.run_start - 1)
+                        .is_some_and(|r| (self.predicate)(left, r) == Ordering::Greater),
+                "merge_join requires LHS sorted consistently with the join predicate"
+            );
+
+            while let Some(r) = self.rhs.get(self.run_start) {
+                if (self.predicate)(left, r) != Ordering::Greater {
+                    break;
+                }
+                self.run_start += 1;
+            }
+
+            // Equal LHS values each start again from the beginning of the run
+            self.current_left = Some(left);
+            self.next_right = self.run_start;
+        }
+    }
+}
+
+/// The intermediate result of a neighbors join that will yield
+/// `(&L, &R, Option<&R>, Option<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedNeighbors<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedNeighbors<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R, Option<&'a R>, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(left) = self.current_left {
+                if let Some((i, r)) =
+                    self.rhs
+                        .next_match(left, &self.predicate, &mut self.rhs_range)
+                {
+                    let before = i.checked_sub(1).and_then(|i| self.rhs.get(i));
+                    return Some((left, r, before, self.rhs.get(i + 1)));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+            self.rhs_range = self.rhs.get_range(left, &self.predicate);
+            self.current_left = Some(left);
+        }
+    }
+}
+
+/// The intermediate result of a cascading join that will yield `(&L, &R, usize)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedCascade<'a, 'p, LIt, R, P, L> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+
+    /// Predicates to try, in priority order
+    predicates: &'p [P],
+
+    /// The current LHS value and the index of the predicate that matched it
+    current_left: Option<(&'a L, usize)>,
+
+    /// The remaining range in RHS to search with the matching predicate
+    rhs_range: (usize, usize),
+}
+
+impl<'a, 'p, LIt, R, P, L> Iterator for JoinedCascade<'a, 'p, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((left, level)) = self.current_left {
+                let predicate = &self.predicates[level];
+                if let Some((_, r)) = self.rhs.next_match(left, predicate, &mut self.rhs_range) {
+                    return Some((left, r, level));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+
+            // Find the first predicate with any match; its first match is yielded right away
+            for (level, predicate) in self.predicates.iter().enumerate() {
+                let mut range = self.rhs.get_range(left, predicate);
+                if let Some((_, r)) = self.rhs.next_match(left, predicate, &mut range) {
+                    self.current_left = Some((left, level));
+                    self.rhs_range = range;
+                    return Some((left, r, level));
+                }
+            }
+        }
+    }
+}
+
+/// The intermediate result of an any-predicate join that will yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedAny<'a, 'p, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+
+    /// Predicates, any of which may match
+    predicates: &'p [P],
+
+    /// The position in RHS to continue scanning from for the current LHS value
+    rhs_pos: usize,
+}
+
+impl<'a, 'p, LIt, R, P, L> Iterator for JoinedAny<'a, 'p, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = match self.current_left {
+                Some(l) => l,
+                None => {
+                    let l = self.lhs_iter.next()?;
+                    self.current_left = Some(l);
+                    self.rhs_pos = 0;
+                    l
+                }
+            };
+
+            while let Some(r) = self.rhs.get(self.rhs_pos) {
+                self.rhs_pos += 1;
+
+                if self.predicates.iter().any(|p| p(left, r).is_eq()) {
+                    return Some((left, r));
+                }
+            }
+
+            // No matches remain for this LHS value
+            self.current_left = None;
+        }
+    }
+}
+
+/// The intermediate result of a nearest-within-group join that will yield `(&L, Option<&R>)`
+/// values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedNearestWithinGroup<'a, LIt, R, P, S> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+
+    /// Determines the group of matches: Fn(&L, &R) -> Ordering
+    primary: P,
+
+    /// Picks the nearest match within the group: Fn(&L, &R) -> Ordering
+    secondary: S,
+}
+
+impl<'a, LIt, R, P, S, L> Iterator for JoinedNearestWithinGroup<'a, LIt, R, P, S>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    S: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use core::cmp::Ordering;
+
+        let left = self.lhs_iter.next()?;
+
+        let mut range = self.rhs.get_range(left, &self.primary);
+        let (mut before, mut after) = (None, None);
+        while let Some((_, r)) = self.rhs.next_match(left, &self.primary, &mut range) {
+            match (self.secondary)(left, r) {
+                Ordering::Equal => return Some((left, Some(r))),
+                Ordering::Greater => before = Some(r),
+                Ordering::Less if after.is_none() => after = Some(r),
+                Ordering::Less => {}
+            }
+        }
+
+        Some((left, before.or(after)))
+    }
+}
+
+/// The intermediate result of a graded join that will yield `(&L, Match<R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGraded<'a, LIt, R, P, S> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Scores a left and right value when there's no exact match: Fn(&L, &R) -> f64
+    fuzzy_score: S,
+
+    /// The minimum score for a fuzzy match
+    threshold: f64,
+}
+
+impl<'a, LIt, R, P, S, L> Iterator for JoinedGraded<'a, LIt, R, P, S>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    S: Fn(&L, &R) -> f64,
+{
+    type Item = (&'a L, Match<'a, R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+
+        let mut range = self.rhs.get_range(left, &self.predicate);
+        if let Some((_, r)) = self.rhs.next_match(left, &self.predicate, &mut range) {
+            return Some((left, Match::Exact(r)));
+        }
+
+        let mut best = Match::None;
+        let mut i = 0;
+        while let Some(r) = self.rhs.get(i) {
+            let score = (self.fuzzy_score)(left, r);
+            let better = match best {
+                Match::Fuzzy(_, b) => score > b,
+                _ => score >= self.threshold,
+            };
+
+            if better {
+                best = Match::Fuzzy(r, score);
+            }
+            i += 1;
+        }
+
+        Some((left, best))
+    }
+}
+
+/// The intermediate result of a [deduplicating join](Joinable::dedup_join) that will yield
+/// `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedDedup<'a, LIt, R, P, L, E> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+
+    /// Whether two right-hand side values are duplicates: Fn(&R, &R) -> bool
+    eq: E,
+
+    /// The matches yielded so far for the current LHS value
+    seen: Vec<&'a R>,
+}
+
+impl<'a, LIt, R, P, L, E> Iterator for JoinedDedup<'a, LIt, R, P, L, E>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    E: Fn(&R, &R) -> bool,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(left) = self.current_left {
+                while let Some((_, r)) =
+                    self.rhs
+                        .next_match(left, &self.predicate, &mut self.rhs_range)
+                {
+                    if !self.seen.iter().any(|seen| (self.eq)(seen, r)) {
+                        self.seen.push(r);
+                        return Some((left, r));
+                    }
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+            self.rhs_range = self.rhs.get_range(left, &self.predicate);
+            self.current_left = Some(left);
+            self.seen.clear();
+        }
+    }
+}
+
+/// The intermediate result of an [indexed join](Joinable::inner_join_indexed) that will yield
+/// `(&L, usize, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedIndexed<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedIndexed<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, usize, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(left) = self.current_left {
+                if let Some((i, r)) =
+                    self.rhs
+                        .next_match(left, &self.predicate, &mut self.rhs_range)
+                {
+                    return Some((left, self.rhs.original_index(i), r));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+            self.rhs_range = self.rhs.get_range(left, &self.predicate);
+            self.current_left = Some(left);
+        }
+    }
+}
+
+/// The intermediate result of a [borrowing grouped join](Joinable::inner_join_grouped_ref) that
+/// will yield `(&L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedRef<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedGroupedRef<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut rs = Vec::new();
+            self.rhs.push_matches(left, &self.predicate, &mut rs);
+            if !rs.is_empty() {
+                return Some((left, rs));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.lhs_iter.size_hint().1)
+    }
+}
+
+/// The intermediate result of a deferred inner join that will yield `(&L, MatchHandle)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedDeferred<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedDeferred<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering + Clone,
+{
+    type Item = (&'a L, MatchHandle<'a, L, R, P>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+            let mut range = self.rhs.get_range(left, &self.predicate);
 
-            match self.rhs {
-                RHS::Unsorted(u) => {
-                    for i in self.rhs_range.0..self.rhs_range.1 {
-                        if (self.predicate)(left, &u[i]).is_eq() {
-                            self.rhs_range.0 = i + 1;
-                            return Some((left, Some(&u[i])));
-                        }
-                    }
+            // Only find the first match, which tells us whether to yield this LHS value at all;
+            // the handle picks up from there
+            if let Some((i, _)) = self.rhs.next_match(left, &self.predicate, &mut range) {
+                let handle = MatchHandle {
+                    left,
+                    rhs: self.rhs,
+                    rhs_range: (i, range.1),
+                    predicate: self.predicate.clone(),
+                };
 
-                    if is_first {
-                        // We pulled the LHS value without any return; indicate no RHS with a None
-                        return Some((left, None));
-                    } else {
-                        // No matches remain for this LHS value
-                        self.current_left.take();
-                    }
-                }
-                RHS::Sorted(s) => {
-                    if self.rhs_range.0 < self.rhs_range.1 {
-                        // we can use one of these values
-                        self.rhs_range.0 += 1;
-                        return Some((left, Some(&s[self.rhs_range.0 - 1])));
-                    } else if is_first {
-                        return Some((left, None));
-                    } else {
-                        self.current_left.take();
-                    }
-                }
+                return Some((left, handle));
             }
         }
     }
 }
 
 #[cfg(test)]
-const LEFT_ITEMS: [(usize, &'static str); 12] = [
+const LEFT_ITEMS: [(usize, &str); 12] = [
     (0, "zero"),
     (0, "nil"),
     (1, "one"),
@@ -215,7 +1864,7 @@ const LEFT_ITEMS: [(usize, &'static str); 12] = [
 ];
 
 #[cfg(test)]
-const RIGHT_ITEMS: [(usize, &'static str); 8] = [
+const RIGHT_ITEMS: [(usize, &str); 8] = [
     (0, "zéro"),
     (1, "un"),
     (1, "uno"),
@@ -249,6 +1898,32 @@ fn test_inner() {
     assert_eq!(joined.next(), None);
 }
 
+#[test]
+fn test_inner_into_iter() {
+    let expected = LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    // Arrays and Vecs are borrowed, whether or not they are referenced explicitly
+    let joined = LEFT_ITEMS
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, expected);
+
+    let left = LEFT_ITEMS.to_vec();
+    let joined = left
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .map(|(l, r)| (*l, *r))
+        .collect::<Vec<_>>();
+    assert_eq!(joined.len(), expected.len());
+
+    let joined = (&left)
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .count();
+    assert_eq!(joined, expected.len());
+}
+
 #[test]
 fn test_inner_sorted() {
     let rhs = RHS::Sorted(&RIGHT_ITEMS);
@@ -333,3 +2008,818 @@ fn test_outer_sorted() {
 
     assert_eq!(joined.next(), None);
 }
+
+#[test]
+fn test_interpolation_join() {
+    let readings = [(10, 1.0), (20, 2.0), (30, 4.0)];
+    let left = [5, 10, 15, 25, 35];
+
+    let mut joined = left
+        .iter()
+        .interpolation_join(RHS::Sorted(&readings), |l, r| l.cmp(&r.0));
+
+    // Below the right range
+    assert_eq!(joined.next(), Some((&5, None)));
+
+    // Exact match
+    assert_eq!(joined.next(), Some((&10, Some((&(10, 1.0), &(10, 1.0))))));
+
+    // Inside the right range
+    assert_eq!(joined.next(), Some((&15, Some((&(10, 1.0), &(20, 2.0))))));
+    assert_eq!(joined.next(), Some((&25, Some((&(20, 2.0), &(30, 4.0))))));
+
+    // Above the right range
+    assert_eq!(joined.next(), Some((&35, None)));
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_merge_join() {
+    let left = [0, 1, 1, 3, 5, 7];
+    let right = [
+        (1, "un"),
+        (1, "uno"),
+        (2, "deux"),
+        (3, "trois"),
+        (7, "sept"),
+    ];
+
+    let joined = left
+        .iter()
+        .merge_join(RHS::new_sorted(&right), |l, r| l.cmp(&r.0))
+        .map(|(l, r)| (*l, r.1))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            (1, "un"),
+            (1, "uno"),
+            (1, "un"),
+            (1, "uno"),
+            (3, "trois"),
+            (7, "sept"),
+        ]
+    );
+
+    // Matches the binary-searching inner join
+    let expected = LEFT_ITEMS
+        .iter()
+        .inner_join(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    let joined = LEFT_ITEMS
+        .iter()
+        .merge_join(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, expected);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "merge_join requires LHS sorted")]
+fn test_merge_join_unsorted_left() {
+    let left = [3, 1];
+    let right = [1, 2, 3];
+    left.iter()
+        .merge_join(RHS::new_sorted(&right), |l, r| l.cmp(r))
+        .for_each(drop);
+}
+
+#[test]
+#[should_panic(expected = "merge_join requires a sorted RHS")]
+fn test_merge_join_partially_sorted() {
+    let right = (0..40).rev().collect::<Vec<_>>();
+    let partial = crate::PartialSort::new(&right, |a, b| a.cmp(b));
+    let _ = [1, 5, 38]
+        .iter()
+        .merge_join(RHS::new_partially_sorted(&partial), |l, r| l.cmp(r));
+}
+
+#[test]
+fn test_asof_join() {
+    // (effective time, setting)
+    let configs = [(10, "a"), (20, "b"), (20, "c"), (30, "d")];
+    let readings = [5, 10, 15, 20, 25, 35];
+
+    let joined = readings
+        .iter()
+        .asof_join(RHS::new_sorted(&configs), |l, r| l.cmp(&r.0))
+        .map(|(l, r)| (*l, r.map(|r| r.1)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            (5, None),
+            (10, Some("a")),
+            (15, Some("a")),
+            (20, Some("c")),
+            (25, Some("c")),
+            (35, Some("d")),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_interpolation_join_unsorted() {
+    let _ = LEFT_ITEMS
+        .iter()
+        .interpolation_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+}
+
+#[test]
+fn test_inner_join_cascade() {
+    use core::cmp::Ordering;
+
+    // (id, name)
+    let people = [(1, "Ann"), (2, "Bob"), (3, "Cy")];
+
+    // (id, name, source)
+    let records = [
+        (1, "Ann", "a"),
+        (9, "Bob", "b"),
+        (8, "Bob", "c"),
+        (1, "Anne", "d"),
+    ];
+
+    type Person = (i32, &'static str);
+    type Record = (i32, &'static str, &'static str);
+    let by_id: fn(&Person, &Record) -> Ordering = |l, r| l.0.cmp(&r.0);
+    let by_name: fn(&Person, &Record) -> Ordering = |l, r| l.1.cmp(r.1);
+    let predicates = [by_id, by_name];
+
+    let joined = people
+        .iter()
+        .inner_join_cascade(&records[..], &predicates)
+        .map(|(l, r, level)| (l.1, r.2, level))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            // Ann matches by id, so the weaker name match is never tried
+            ("Ann", "a", 0),
+            ("Ann", "d", 0),
+            // Bob has no id match and falls back to name
+            ("Bob", "b", 1),
+            ("Bob", "c", 1),
+        ]
+    );
+}
+
+#[test]
+fn test_dedup_join() {
+    let left = [1, 2, 3];
+    let right = [
+        (1, "un"),
+        (1, "uno"),
+        (1, "un"),
+        (2, "deux"),
+        (2, "deux"),
+        (3, "trois"),
+    ];
+
+    let expected = vec![(1, "un"), (1, "uno"), (2, "deux"), (3, "trois")];
+    for rhs in [RHS::new_unsorted(&right), RHS::new_sorted(&right)] {
+        let joined = left
+            .iter()
+            .dedup_join(rhs, |l, r| l.cmp(&r.0), |a, b| a == b)
+            .map(|(l, r)| (*l, r.1))
+            .collect::<Vec<_>>();
+        assert_eq!(joined, expected);
+    }
+
+    // Distinct by the given equality rather than by the join key
+    let joined = left
+        .iter()
+        .dedup_join(&right[..], |l, r| l.cmp(&r.0), |a, b| a.0 == b.0)
+        .count();
+    assert_eq!(joined, 3);
+}
+
+#[test]
+fn test_inner_join_indexed() {
+    let left = [1, 2, 5];
+    let right = [(2, "deux"), (1, "un"), (2, "dos"), (3, "trois")];
+    let joined = left
+        .iter()
+        .inner_join_indexed(&right[..], |l, r| l.cmp(&r.0))
+        .map(|(l, i, r)| (*l, i, r.1))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec![(1, 1, "un"), (2, 0, "deux"), (2, 2, "dos")]);
+
+    let sorted = [(1, "un"), (2, "deux"), (2, "dos"), (3, "trois")];
+    let joined = left
+        .iter()
+        .inner_join_indexed(RHS::new_sorted(&sorted), |l, r| l.cmp(&r.0))
+        .map(|(l, i, r)| (*l, i, r.1))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec![(1, 0, "un"), (2, 1, "deux"), (2, 2, "dos")]);
+
+    // Indices refer to the wrapped slice, not to its sorted order
+    let partial = crate::PartialSort::new(&right, |a, b| a.0.cmp(&b.0));
+    let joined = left
+        .iter()
+        .inner_join_indexed(RHS::new_partially_sorted(&partial), |l, r| l.cmp(&r.0))
+        .map(|(l, i, r)| {
+            assert_eq!(&right[i], r);
+            (*l, i)
+        })
+        .count();
+    assert_eq!(joined, 3);
+}
+
+#[test]
+fn test_inner_join_grouped_ref() {
+    use crate::JoinableGrouped;
+
+    fn by_id(l: &(usize, &str), r: &(usize, &str)) -> core::cmp::Ordering {
+        l.0.cmp(&r.0)
+    }
+
+    // The same predicate works for grouped and ungrouped joins
+    let ungrouped = LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], by_id)
+        .count();
+    let grouped = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped_ref(&RIGHT_ITEMS[..], by_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        grouped.iter().map(|(_, rs)| rs.len()).sum::<usize>(),
+        ungrouped
+    );
+
+    let owned = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(RHS::new_sorted(&RIGHT_ITEMS), |l, r| by_id(l, r))
+        .collect::<Vec<_>>();
+    let grouped = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped_ref(RHS::new_sorted(&RIGHT_ITEMS), by_id)
+        .collect::<Vec<_>>();
+    assert_eq!(grouped, owned);
+    assert_eq!(grouped[0], (&(0, "zero"), vec![&(0, "zéro")]));
+}
+
+#[test]
+fn test_graded_join() {
+    let names = ["Smith", "Jonson", "Zed"];
+    let people = ["Johnson", "Smith", "Smyth"];
+
+    // The fraction of positions at which the two names have the same letter
+    let similarity = |l: &&str, r: &&str| {
+        let same = l.chars().zip(r.chars()).filter(|(a, b)| a == b).count();
+        same as f64 / l.len().max(r.len()) as f64
+    };
+
+    let mut joined = names
+        .iter()
+        .graded_join(&people[..], |l, r| l.cmp(r), similarity, 0.25);
+
+    assert_eq!(joined.next(), Some((&"Smith", Match::Exact(&"Smith"))));
+    assert_eq!(
+        joined.next(),
+        Some((&"Jonson", Match::Fuzzy(&"Johnson", 2.0 / 7.0)))
+    );
+    assert_eq!(joined.next(), Some((&"Zed", Match::None)));
+    assert_eq!(joined.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_inner_join_checked() {
+    let (joined, duplicates) =
+        LEFT_ITEMS
+            .iter()
+            .inner_join_checked(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), |l| l.0);
+
+    // Nothing has been pulled yet
+    assert!(duplicates.is_empty());
+
+    assert_eq!(joined.count(), 9);
+    assert_eq!(duplicates.keys(), vec![0]);
+}
+
+#[test]
+fn test_inner_join_deferred() {
+    use core::cell::Cell;
+
+    // Count comparisons so we can tell that matches aren't enumerated until they're iterated
+    let comparisons = Cell::new(0);
+    let predicate = |l: &(usize, &str), r: &(usize, &str)| {
+        comparisons.set(comparisons.get() + 1);
+        l.0.cmp(&r.0)
+    };
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join_deferred(&RIGHT_ITEMS[..], &predicate);
+
+    // Finding the first left value stops at its first match
+    let (left, matches) = joined.next().unwrap();
+    assert_eq!(left, &(0, "zero"));
+    assert_eq!(comparisons.get(), 1);
+
+    assert_eq!(matches.collect::<Vec<_>>(), vec![&(0, "zéro")]);
+    assert_eq!(comparisons.get(), 1 + RIGHT_ITEMS.len());
+
+    let remaining = joined
+        .map(|(l, matches)| (l.1, matches.map(|r| r.1).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        remaining,
+        vec![
+            ("nil", vec!["zéro"]),
+            ("one", vec!["un", "uno", "ichi"]),
+            ("two", vec!["dos", "deux"]),
+            ("three", vec!["trois"]),
+            ("four", vec!["quatre"]),
+        ]
+    );
+}
+
+#[test]
+fn test_inner_join_deferred_sorted() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_deferred(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0))
+        .map(|(l, matches)| (l.1, matches.count()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            ("zero", 1),
+            ("nil", 1),
+            ("one", 3),
+            ("two", 2),
+            ("three", 1),
+            ("four", 1)
+        ]
+    );
+}
+
+#[test]
+fn test_inner_join_any() {
+    use core::cmp::Ordering;
+
+    let people = [("Ann", "ann@example.com"), ("Bob", "bob@example.com")];
+    let accounts = [
+        ("Ann", "ann@example.com"),
+        ("Robert", "bob@example.com"),
+        ("Bob", "robert@example.com"),
+        ("Cat", "cat@example.com"),
+    ];
+
+    type Person = (&'static str, &'static str);
+    let by_name: fn(&Person, &Person) -> Ordering = |p, a| p.0.cmp(a.0);
+    let by_email: fn(&Person, &Person) -> Ordering = |p, a| p.1.cmp(a.1);
+    let predicates = [by_name, by_email];
+
+    let joined = people
+        .iter()
+        .inner_join_any(&accounts[..], &predicates)
+        .map(|(p, a)| (p.0, a.0))
+        .collect::<Vec<_>>();
+
+    // Ann matches on both predicates but is yielded once; Robert matches only on email
+    assert_eq!(
+        joined,
+        vec![("Ann", "Ann"), ("Bob", "Robert"), ("Bob", "Bob")]
+    );
+}
+
+#[test]
+fn test_collect_into() {
+    let mut out = Vec::with_capacity(16);
+    LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect_into(&mut out);
+
+    assert_eq!(out.len(), 9);
+    let (capacity, ptr) = (out.capacity(), out.as_ptr());
+
+    LEFT_ITEMS
+        .iter()
+        .skip(5)
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect_into(&mut out);
+
+    assert_eq!(out, vec![(&(4, "four"), &(4, "quatre"))]);
+    assert_eq!(out.capacity(), capacity);
+    assert_eq!(out.as_ptr(), ptr);
+}
+
+#[test]
+fn test_skip_left_if() {
+    use core::cell::RefCell;
+
+    // Record which LHS values RHS is searched for
+    let searched = RefCell::new(Vec::new());
+    let predicate = |l: &(usize, &str), r: &(usize, &str)| {
+        searched.borrow_mut().push(l.0);
+        l.0.cmp(&r.0)
+    };
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], &predicate)
+        .skip_left_if(|l| l.0 % 2 == 1)
+        .map(|(l, r)| (l.1, r.1))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            ("zero", "zéro"),
+            ("nil", "zéro"),
+            ("two", "dos"),
+            ("two", "deux"),
+            ("four", "quatre")
+        ]
+    );
+    assert!(searched.borrow().iter().all(|k| k % 2 == 0));
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .take(5)
+        .outer_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .skip_left_if(|l| l.0 < 3)
+        .map(|(l, r)| (l.1, r.map(|r| r.1)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined, vec![("three", Some("trois"))]);
+}
+
+#[test]
+fn test_inner_join_checked_sorted() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_checked_sorted(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(joined.len(), 9);
+
+    // Comparing the wrong way around is inconsistent with the order of RHS
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join_checked_sorted(RHS::new_sorted(&RIGHT_ITEMS), |l, r| r.0.cmp(&l.0));
+    assert_eq!(joined.next(), Some(Err(SortError { rhs_index: 0 })));
+    assert_eq!(joined.next(), None);
+
+    // RHS claims to be sorted but isn't
+    let right = [(1, "un"), (3, "trois"), (2, "deux"), (4, "quatre")];
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_checked_sorted(RHS::new_sorted(&right), |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            Ok((&(1, "one"), &(1, "un"))),
+            Err(SortError { rhs_index: 1 })
+        ]
+    );
+}
+
+#[test]
+fn test_nearest_within_group_join() {
+    // (sensor, minute)
+    let lookups = [("a", 20), ("a", 25), ("a", 5), ("b", 10), ("c", 0)];
+
+    // (sensor, minute, reading)
+    let readings = [
+        ("a", 10, 1.0),
+        ("a", 20, 2.0),
+        ("a", 30, 3.0),
+        ("b", 15, 4.0),
+    ];
+
+    let joined = lookups
+        .iter()
+        .nearest_within_group_join(
+            RHS::new_sorted(&readings),
+            |l, r| l.0.cmp(r.0),
+            |l, r| l.1.cmp(&r.1),
+        )
+        .map(|(l, r)| (l.1, r.map(|r| r.2)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            (20, Some(2.0)),
+            (25, Some(2.0)),
+            (5, Some(1.0)),
+            (10, Some(4.0)),
+            (0, None)
+        ]
+    );
+}
+
+#[test]
+fn test_narrower_lifetimes() {
+    // These only need to compile: each narrows a longer lifetime to a shorter one
+    fn narrow_iter<'long: 'short, 'short, LIt, R, P, L>(
+        joined: JoinedEachInner<'long, LIt, R, P, L>,
+    ) -> JoinedEachInner<'short, LIt, R, P, L> {
+        joined
+    }
+
+    fn narrow_rhs<'long: 'short, 'short, R>(rhs: RHS<'long, R>) -> RHS<'short, R> {
+        rhs
+    }
+
+    type Item = (usize, &'static str);
+    fn scoped<'b>(seen: &mut Vec<&'b Item>, r: &'b Item) {
+        seen.push(r);
+    }
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join(narrow_rhs(RHS::new_sorted(&RIGHT_ITEMS)), |l, r| {
+            l.0.cmp(&r.0)
+        });
+
+    // `seen` holds references that only live as long as `local`
+    let local = (99, "local");
+    let mut seen = vec![&local];
+    for (_, r) in narrow_iter(joined).take(2) {
+        scoped(&mut seen, r);
+    }
+
+    assert_eq!(seen, vec![&(99, "local"), &(0, "zéro"), &(0, "zéro")]);
+}
+
+#[test]
+fn test_inner_join_with_neighbors() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_with_neighbors(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0))
+        .map(|(l, r, before, after)| (l.1, r.1, before.map(|b| b.1), after.map(|a| a.1)))
+        .collect::<Vec<_>>();
+
+    // The first and last values in RHS have no neighbor on one side
+    assert_eq!(joined[0], ("zero", "zéro", None, Some("un")));
+    assert_eq!(joined[3], ("one", "uno", Some("un"), Some("ichi")));
+    assert_eq!(joined[8], ("four", "quatre", Some("trois"), None));
+    assert_eq!(joined.len(), 9);
+}
+
+#[test]
+fn test_comparison_budget() {
+    // Every LHS value is compared against every RHS value
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .comparison_budget(2 * RIGHT_ITEMS.len() + 2);
+
+    assert_eq!(joined.next(), Some((&(0, "zero"), &(0, "zéro"))));
+    assert_eq!(joined.next(), Some((&(0, "nil"), &(0, "zéro"))));
+    assert_eq!(joined.next(), Some((&(1, "one"), &(1, "un"))));
+    assert!(!joined.budget_exhausted());
+
+    assert_eq!(joined.next(), None);
+    assert!(joined.budget_exhausted());
+    assert_eq!(joined.next(), None);
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .comparison_budget(1000);
+    assert_eq!(joined.by_ref().count(), 9);
+    assert!(!joined.budget_exhausted());
+
+    // The budget runs out partway through the first binary search
+    let right = (0..100).collect::<Vec<_>>();
+    let mut joined = [50, 60, 70]
+        .iter()
+        .inner_join(RHS::new_sorted(&right), |l, r| l.cmp(r))
+        .comparison_budget(2);
+    assert_eq!(joined.next(), None);
+    assert!(joined.budget_exhausted());
+}
+
+#[test]
+fn test_reuse_repeated_left() {
+    let comparisons = Cell::new(0);
+    let predicate = |l: &usize, r: &(usize, &str)| {
+        comparisons.set(comparisons.get() + 1);
+        l.cmp(&r.0)
+    };
+
+    let left = [1, 1, 1, 2, 5, 5, 1];
+    let expected = left
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    let joined = left
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], predicate)
+        .reuse_repeated_left()
+        .collect::<Vec<_>>();
+    assert_eq!(joined, expected);
+
+    // Full scans for the first 1, 2, each 5, and the last 1, plus a check of each left value that
+    // follows one with matches: the repeated 1s, 2, and the first 5
+    let n = RIGHT_ITEMS.len();
+    assert_eq!(comparisons.get(), 5 * n + 4);
+}
+
+#[test]
+fn test_outer_join_sentinel() {
+    static UNKNOWN: (usize, &str) = (usize::MAX, "?");
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .skip(4)
+        .take(3)
+        .outer_join_sentinel(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), &UNKNOWN)
+        .map(|(l, r)| (l.1, r))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            ("three", &(3, "trois")),
+            ("four", &(4, "quatre")),
+            ("five", &UNKNOWN)
+        ]
+    );
+    assert!(core::ptr::eq(joined[2].1, &UNKNOWN));
+}
+
+#[test]
+fn test_outer_sorted_inconsistent_predicate() {
+    let right = RHS::new_sorted(&RIGHT_ITEMS);
+
+    // A predicate that matches everything gives a range spanning all of RHS
+    let joined = LEFT_ITEMS
+        .iter()
+        .take(2)
+        .outer_join(right, |_, _| core::cmp::Ordering::Equal)
+        .collect::<Vec<_>>();
+    assert_eq!(joined.len(), 2 * RIGHT_ITEMS.len());
+    assert!(joined.iter().all(|(_, r)| r.is_some()));
+
+    // A predicate inconsistent with the sort order must neither index out of bounds, yield more
+    // matches than RHS has, nor skip the None for an unmatched left. Debug builds instead panic
+    // as soon as a search finds the inconsistency.
+    let parity = |l: &(usize, &str), r: &(usize, &str)| match (l.0 + r.1.len()) % 3 {
+        0 => core::cmp::Ordering::Less,
+        1 => core::cmp::Ordering::Equal,
+        _ => core::cmp::Ordering::Greater,
+    };
+    let chunks = [&RIGHT_ITEMS[..3], &RIGHT_ITEMS[3..]];
+    for right in [right, RHS::new_sorted_chunks(&chunks)] {
+        for left in LEFT_ITEMS.iter() {
+            let joined = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                core::iter::once(left)
+                    .outer_join(right, parity)
+                    .collect::<Vec<_>>()
+            }));
+
+            match joined {
+                Ok(joined) => {
+                    assert!(!joined.is_empty() && joined.len() <= RIGHT_ITEMS.len());
+                    assert!(joined.len() == 1 || joined.iter().all(|(_, r)| r.is_some()));
+                }
+                Err(panic) => {
+                    let message = panic.downcast_ref::<String>().unwrap();
+                    assert!(cfg!(debug_assertions) && message.contains("not sorted"));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_full_outer_join() {
+    let left = [(0, "zero"), (2, "two"), (5, "five")];
+    let expected = vec![
+        (Some("zero"), Some("zéro")),
+        (Some("two"), Some("dos")),
+        (Some("two"), Some("deux")),
+        (Some("five"), None),
+        (None, Some("un")),
+        (None, Some("uno")),
+        (None, Some("ichi")),
+        (None, Some("trois")),
+        (None, Some("quatre")),
+    ];
+
+    for rhs in [
+        RHS::new_unsorted(&RIGHT_ITEMS),
+        RHS::new_sorted(&RIGHT_ITEMS),
+    ] {
+        let joined = left
+            .iter()
+            .full_outer_join(rhs, |l, r| l.0.cmp(&r.0))
+            .map(|(l, r)| (l.map(|l| l.1), r.map(|r| r.1)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(joined, expected);
+    }
+
+    let joined = core::iter::empty()
+        .full_outer_join(&RIGHT_ITEMS[..2], |l: &(usize, &str), r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        joined,
+        vec![(None, Some(&RIGHT_ITEMS[0])), (None, Some(&RIGHT_ITEMS[1]))]
+    );
+
+    // Unmatched RHS values are yielded in their original order, not their sorted order
+    let right = [(3, "trois"), (0, "zéro"), (4, "quatre"), (1, "un")];
+    let partial = crate::PartialSort::new(&right, |a, b| a.0.cmp(&b.0));
+    let joined = [(0, "zero"), (5, "five")]
+        .iter()
+        .full_outer_join(RHS::new_partially_sorted(&partial), |l, r| l.0.cmp(&r.0))
+        .map(|(l, r)| (l.map(|l| l.1), r.map(|r| r.1)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        joined,
+        vec![
+            (Some("zero"), Some("zéro")),
+            (Some("five"), None),
+            (None, Some("trois")),
+            (None, Some("quatre")),
+            (None, Some("un")),
+        ]
+    );
+}
+
+#[test]
+fn test_right_outer_join() {
+    let left = [(0, "zero"), (2, "two"), (2, "deux"), (5, "five")];
+
+    for rhs in [
+        RHS::new_unsorted(&RIGHT_ITEMS[..5]),
+        RHS::new_sorted(&RIGHT_ITEMS[..5]),
+    ] {
+        let joined = left
+            .iter()
+            .right_outer_join(rhs, |l, r| l.0.cmp(&r.0))
+            .map(|(l, r)| (l.map(|l| l.1), r.1))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            joined,
+            vec![
+                (Some("zero"), "zéro"),
+                (None, "un"),
+                (None, "uno"),
+                (None, "ichi"),
+                (Some("two"), "dos"),
+                (Some("deux"), "dos"),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_rhs_without_debug() {
+    struct Opaque(usize);
+    let right = [Opaque(1), Opaque(2), Opaque(2)];
+
+    let inner = LEFT_ITEMS
+        .iter()
+        .inner_join(&right[..], |l, r| l.0.cmp(&r.0))
+        .count();
+    assert_eq!(inner, 3);
+
+    let outer = LEFT_ITEMS
+        .iter()
+        .outer_join(&right[..], |l, r| l.0.cmp(&r.0))
+        .filter(|(_, r)| r.is_some())
+        .count();
+    assert_eq!(outer, 3);
+}
+
+#[test]
+fn test_fused() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_fused(&joined);
+    assert_eq!(joined.by_ref().count(), 9);
+    assert_eq!(joined.next(), None);
+    assert_eq!(joined.next(), None);
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .outer_join(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0));
+    assert_fused(&joined);
+    assert_eq!(joined.by_ref().count(), 15);
+    assert_eq!(joined.next(), None);
+    assert_eq!(joined.next(), None);
+}
@@ -0,0 +1,1003 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::joined::{Joinable, JoinedEachInner};
+#[cfg(feature = "std")]
+use crate::rhs::HashedRHS;
+use crate::RHS;
+
+/// The indices in RHS of each right-hand key, for [JoinStrategy::HashLookup]. Without the `std`
+/// feature, there's no hash map, so the keys are ordered instead.
+#[cfg(feature = "std")]
+type KeyIndex<K> = HashMap<K, Vec<usize>>;
+#[cfg(not(feature = "std"))]
+type KeyIndex<K> = BTreeMap<K, Vec<usize>>;
+
+/// How a [key-based join](JoinableByKey::inner_join_with_strategy) searches RHS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinStrategy {
+    /// Compare every record in RHS against each left record. Searches are O(n).
+    LinearScan,
+
+    /// Binary search RHS, which must be sorted by the right-hand key. Searches are O(lg n).
+    BinarySearch,
+
+    /// Build a hash index of the right-hand keys up front. Searches are O(1) on average, or
+    /// O(lg n) without the `std` feature, when the index is a `BTreeMap`.
+    HashLookup,
+}
+
+/// A trait allowing the joining of a left-hand side (LHS) and a right-hand side ([RHS]) dataset
+/// by extracting a key from each side rather than writing a single `Fn(&L, &R) -> Ordering`
+/// predicate.
+///
+/// As with [Joinable](crate::Joinable), LHS values are borrowed. If RHS is
+/// [sorted](RHS::Sorted), it must be sorted by the right-hand key.
+pub trait JoinableByKey<'a, LIt, L> {
+    /// Joins LHS and RHS on keys extracted from each side, keeping only records from left that
+    /// have one or more matches in right.
+    ///
+    /// A left and right record match when `left_key` and `right_key` return equal keys, like SQL's
+    /// `ON l.x = r.y`, so no `Ordering` predicate needs to be written. Each match is yielded as
+    /// `(&L, &R)`, as with [inner_join](crate::Joinable::inner_join).
+    ///
+    /// To join on several fields, return a tuple of them from each key function. Tuples compare
+    /// field by field, so a sorted RHS must be sorted by the first field, then the second, and so
+    /// on.
+    fn inner_join_by_key<R, K, LK, RK>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        left_key: LK,
+        right_key: RK,
+    ) -> JoinedByKey<'a, LIt, R, K, LK, RK, L>
+    where
+        R: 'a,
+        K: Ord,
+        LK: Fn(&L) -> K,
+        RK: Fn(&R) -> K;
+
+    /// Joins LHS and RHS when both sides are the same type, matching records that are equal, like
+    /// SQL's `NATURAL JOIN`.
+    ///
+    /// This is [inner_join](crate::Joinable::inner_join) with [Ord::cmp] as the predicate, so no
+    /// closure needs to be written. Each match is yielded as `(&L, &L)`. If RHS is
+    /// [sorted](RHS::Sorted), it must be sorted by [Ord].
+    #[allow(clippy::type_complexity)]
+    fn natural_inner_join(
+        self,
+        rhs: impl Into<RHS<'a, L>>,
+    ) -> JoinedEachInner<'a, LIt, L, fn(&L, &L) -> Ordering, L>
+    where
+        L: Ord;
+
+    /// Joins LHS and RHS on keys extracted from each side, keeping only records from left that
+    /// have one or more matches in right.
+    ///
+    /// `cmp` compares the left key against the right key. Each match is yielded as
+    /// `(&L, &R, K)`, where `K` is the key extracted from the left record, so it doesn't need to
+    /// be re-extracted downstream.
+    fn inner_join_keyed<R, K, LK, RK, C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        left_key: LK,
+        right_key: RK,
+        cmp: C,
+    ) -> JoinedKeyed<'a, LIt, R, K, LK, RK, C, L>
+    where
+        R: 'a,
+        K: Clone,
+        LK: Fn(&L) -> K,
+        RK: Fn(&R) -> K,
+        C: Fn(&K, &K) -> Ordering;
+
+    /// Joins LHS and a slice of right records on keys extracted from each side, searching RHS
+    /// with the chosen [JoinStrategy], and keeping only records from left that have one or more
+    /// matches in right.
+    ///
+    /// This makes it easy to switch between, or benchmark, strategies over the same data. Each
+    /// match is yielded as `(&L, &R)`, in RHS order for any strategy.
+    ///
+    /// # Panics
+    /// Panics if [JoinStrategy::BinarySearch] is chosen but RHS is not sorted by `right_key`.
+    fn inner_join_with_strategy<R, K, LK, RK>(
+        self,
+        rhs: &'a [R],
+        left_key: LK,
+        right_key: RK,
+        strategy: JoinStrategy,
+    ) -> JoinedWithStrategy<'a, LIt, R, K, LK, RK, L>
+    where
+        R: 'a,
+        K: Ord + Hash,
+        LK: Fn(&L) -> K,
+        RK: Fn(&R) -> K;
+
+    /// Joins LHS and a slice of right records that each define an inclusive `[start, end]` range
+    /// of keys, matching each left record to every right record whose range contains its key.
+    ///
+    /// RHS must be sorted by the start of its ranges. Ranges may overlap, in which case every
+    /// containing record is yielded, in RHS order. Each match is yielded as `(&L, &R)`.
+    ///
+    /// Candidates are found by binary searching the range starts and the running maximum of the
+    /// range ends, which is computed once up front.
+    fn range_contains_join<R, K, KF, RF>(
+        self,
+        rhs: &'a [R],
+        key: KF,
+        range: RF,
+    ) -> JoinedRangeContains<'a, LIt, R, K, KF, RF, L>
+    where
+        R: 'a,
+        K: Ord + Clone,
+        KF: Fn(&L) -> K,
+        RF: Fn(&R) -> (K, K);
+
+    /// Joins LHS and a hash-indexed RHS, keeping only records from left that have one or more
+    /// matches in right.
+    ///
+    /// A left and right record match when `left_key` returns the key the right record was
+    /// indexed by. Each probe of RHS is amortized O(1). Each match is yielded as `(&L, &R)`, with
+    /// the matches of each left record in RHS order.
+    #[cfg(feature = "std")]
+    fn inner_join_hashed<R, K, LK>(
+        self,
+        rhs: &'a HashedRHS<'a, R, K>,
+        left_key: LK,
+    ) -> JoinedHashed<'a, LIt, R, K, LK, L>
+    where
+        K: Hash + Eq,
+        LK: Fn(&L) -> K;
+
+    /// Joins LHS and a hash-indexed RHS as [inner_join_hashed](JoinableByKey::inner_join_hashed)
+    /// does, keeping all records from left.
+    ///
+    /// Each match is yielded as `(&L, Some(&R))`, and each record from left with no matches as
+    /// `(&L, None)`.
+    #[cfg(feature = "std")]
+    fn outer_join_hashed<R, K, LK>(
+        self,
+        rhs: &'a HashedRHS<'a, R, K>,
+        left_key: LK,
+    ) -> JoinedHashedOuter<'a, LIt, R, K, LK, L>
+    where
+        K: Hash + Eq,
+        LK: Fn(&L) -> K;
+
+    /// Joins LHS and the entries of a [BTreeMap], keeping only records from left whose key is in
+    /// the map.
+    ///
+    /// A left record matches the entry whose key equals the one `left_key` returns, found with
+    /// the map's own O(lg n) lookup, so a right table already kept in a map needn't be copied into
+    /// a slice. Each match is yielded as `(&L, &V)`; for a map of `Vec<R>`, this gives every right
+    /// record for the key at once.
+    fn inner_join_btreemap<K, V, LK>(
+        self,
+        rhs: &'a BTreeMap<K, V>,
+        left_key: LK,
+    ) -> JoinedBTreeMap<'a, LIt, K, V, LK>
+    where
+        K: Ord,
+        LK: Fn(&L) -> K;
+}
+
+impl<'a, LIt, L> JoinableByKey<'a, LIt, L> for LIt
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+{
+    fn inner_join_by_key<R, K, LK, RK>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        left_key: LK,
+        right_key: RK,
+    ) -> JoinedByKey<'a, LIt, R, K, LK, RK, L>
+    where
+        R: 'a,
+        K: Ord,
+        LK: Fn(&L) -> K,
+        RK: Fn(&R) -> K,
+    {
+        JoinedByKey {
+            lhs_iter: self,
+            current_left: None,
+            rhs: rhs.into(),
+            rhs_range: (1, 0),
+            left_key,
+            right_key,
+        }
+    }
+
+    fn natural_inner_join(
+        self,
+        rhs: impl Into<RHS<'a, L>>,
+    ) -> JoinedEachInner<'a, LIt, L, fn(&L, &L) -> Ordering, L>
+    where
+        L: Ord,
+    {
+        self.inner_join(rhs, L::cmp)
+    }
+
+    fn inner_join_keyed<R, K, LK, RK, C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        left_key: LK,
+        right_key: RK,
+        cmp: C,
+    ) -> JoinedKeyed<'a, LIt, R, K, LK, RK, C, L>
+    where
+        R: 'a,
+        K: Clone,
+        LK: Fn(&L) -> K,
+        RK: Fn(&R) -> K,
+        C: Fn(&K, &K) -> Ordering,
+    {
+        JoinedKeyed {
+            lhs_iter: self,
+            rhs: rhs.into(),
+            rhs_range: (1, 0),
+            current_left: None,
+            left_key,
+            right_key,
+            cmp,
+        }
+    }
+
+    fn inner_join_with_strategy<R, K, LK, RK>(
+        self,
+        rhs: &'a [R],
+        left_key: LK,
+        right_key: RK,
+        strategy: JoinStrategy,
+    ) -> JoinedWithStrategy<'a, LIt, R, K, LK, RK, L>
+    where
+        R: 'a,
+        K: Ord + Hash,
+        LK: Fn(&L) -> K,
+        RK: Fn(&R) -> K,
+    {
+        let (search, index) = match strategy {
+            JoinStrategy::LinearScan => (RHS::Unsorted(rhs), None),
+            JoinStrategy::BinarySearch => {
+                assert!(
+                    rhs.windows(2).all(|w| right_key(&w[0]) <= right_key(&w[1])),
+                    "JoinStrategy::BinarySearch requires RHS sorted by the right-hand key"
+                );
+                (RHS::Sorted(rhs), None)
+            }
+            JoinStrategy::HashLookup => {
+                let mut index = KeyIndex::new();
+                for (i, r) in rhs.iter().enumerate() {
+                    index.entry(right_key(r)).or_default().push(i);
+                }
+                (RHS::Unsorted(rhs), Some(index))
+            }
+        };
+
+        JoinedWithStrategy {
+            lhs_iter: self,
+            rhs: search,
+            index,
+            rhs_range: (1, 0),
+            current_left: None,
+            left_key,
+            right_key,
+        }
+    }
+
+    fn range_contains_join<R, K, KF, RF>(
+        self,
+        rhs: &'a [R],
+        key: KF,
+        range: RF,
+    ) -> JoinedRangeContains<'a, LIt, R, K, KF, RF, L>
+    where
+        R: 'a,
+        K: Ord + Clone,
+        KF: Fn(&L) -> K,
+        RF: Fn(&R) -> (K, K),
+    {
+        let mut max_ends: Vec<K> = Vec::with_capacity(rhs.len());
+        for r in rhs {
+            let (_, end) = range(r);
+            let max_end = match max_ends.last() {
+                Some(max_end) if *max_end > end => max_end.clone(),
+                _ => end,
+            };
+            max_ends.push(max_end);
+        }
+
+        JoinedRangeContains {
+            lhs_iter: self,
+            current_left: None,
+            rhs,
+            max_ends,
+            rhs_range: (1, 0),
+            key,
+            range,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn inner_join_hashed<R, K, LK>(
+        self,
+        rhs: &'a HashedRHS<'a, R, K>,
+        left_key: LK,
+    ) -> JoinedHashed<'a, LIt, R, K, LK, L>
+    where
+        K: Hash + Eq,
+        LK: Fn(&L) -> K,
+    {
+        JoinedHashed {
+            lhs_iter: self,
+            current_left: None,
+            rhs,
+            left_key,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn outer_join_hashed<R, K, LK>(
+        self,
+        rhs: &'a HashedRHS<'a, R, K>,
+        left_key: LK,
+    ) -> JoinedHashedOuter<'a, LIt, R, K, LK, L>
+    where
+        K: Hash + Eq,
+        LK: Fn(&L) -> K,
+    {
+        JoinedHashedOuter {
+            inner: self.inner_join_hashed(rhs, left_key),
+        }
+    }
+
+    fn inner_join_btreemap<K, V, LK>(
+        self,
+        rhs: &'a BTreeMap<K, V>,
+        left_key: LK,
+    ) -> JoinedBTreeMap<'a, LIt, K, V, LK>
+    where
+        K: Ord,
+        LK: Fn(&L) -> K,
+    {
+        JoinedBTreeMap {
+            lhs_iter: self,
+            rhs,
+            left_key,
+        }
+    }
+}
+
+/// The intermediate result of an [inner join by key](JoinableByKey::inner_join_by_key) that will
+/// yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedByKey<'a, LIt, R, K, LK, RK, L> {
+    lhs_iter: LIt,
+
+    /// The current LHS value along with its extracted key.
+    current_left: Option<(&'a L, K)>,
+
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    left_key: LK,
+    right_key: RK,
+}
+
+impl<'a, LIt, R, K, LK, RK, L> Iterator for JoinedByKey<'a, LIt, R, K, LK, RK, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    K: Ord,
+    LK: Fn(&L) -> K,
+    RK: Fn(&R) -> K,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = |k: &K, r: &R| k.cmp(&(self.right_key)(r));
+
+        loop {
+            if let Some((left, key)) = &self.current_left {
+                if let Some((_, r)) = self.rhs.next_match(key, &predicate, &mut self.rhs_range) {
+                    return Some((left, r));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let l = self.lhs_iter.next()?;
+            let key = (self.left_key)(l);
+            self.rhs_range = self.rhs.get_range(&key, &predicate);
+            self.current_left = Some((l, key));
+        }
+    }
+}
+
+/// The intermediate result of a key-based inner join that will yield `(&L, &R, K)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedKeyed<'a, LIt, R, K, LK, RK, C, L> {
+    lhs_iter: LIt,
+
+    /// The current LHS value along with its extracted key.
+    current_left: Option<(&'a L, K)>,
+
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    left_key: LK,
+    right_key: RK,
+    cmp: C,
+}
+
+impl<'a, LIt, R, K, LK, RK, C, L> Iterator for JoinedKeyed<'a, LIt, R, K, LK, RK, C, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    K: Clone,
+    LK: Fn(&L) -> K,
+    RK: Fn(&R) -> K,
+    C: Fn(&K, &K) -> Ordering,
+{
+    type Item = (&'a L, &'a R, K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = |k: &K, r: &R| (self.cmp)(k, &(self.right_key)(r));
+
+        loop {
+            if self.current_left.is_none() {
+                let l = self.lhs_iter.next()?;
+                let key = (self.left_key)(l);
+                self.rhs_range = self.rhs.get_range(&key, &predicate);
+                self.current_left = Some((l, key));
+            }
+
+            let (left, key) = self.current_left.as_ref().unwrap();
+
+            if let Some((_, r)) = self.rhs.next_match(key, &predicate, &mut self.rhs_range) {
+                return Some((left, r, key.clone()));
+            }
+
+            // No matches remain for this LHS value
+            self.current_left = None;
+        }
+    }
+}
+
+/// The intermediate result of a key-based join using a [JoinStrategy] that will yield `(&L, &R)`
+/// values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedWithStrategy<'a, LIt, R, K, LK, RK, L> {
+    lhs_iter: LIt,
+
+    /// The current LHS value along with its extracted key.
+    current_left: Option<(&'a L, K)>,
+
+    rhs: RHS<'a, R>,
+
+    /// For [JoinStrategy::HashLookup], the indices in RHS of each right-hand key
+    index: Option<KeyIndex<K>>,
+
+    /// The remaining range to search in RHS or, with an index, in the current key's indices
+    rhs_range: (usize, usize),
+    left_key: LK,
+    right_key: RK,
+}
+
+impl<'a, LIt, R, K, LK, RK, L> Iterator for JoinedWithStrategy<'a, LIt, R, K, LK, RK, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    K: Ord + Hash,
+    LK: Fn(&L) -> K,
+    RK: Fn(&R) -> K,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = |k: &K, r: &R| k.cmp(&(self.right_key)(r));
+
+        loop {
+            if let Some((left, key)) = &self.current_left {
+                let found = match &self.index {
+                    Some(index) => {
+                        let i = index.get(key).and_then(|is| is.get(self.rhs_range.0));
+                        self.rhs_range.0 += 1;
+                        i.and_then(|&i| self.rhs.get(i))
+                    }
+                    None => self
+                        .rhs
+                        .next_match(key, &predicate, &mut self.rhs_range)
+                        .map(|(_, r)| r),
+                };
+
+                if let Some(r) = found {
+                    return Some((left, r));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let l = self.lhs_iter.next()?;
+            let key = (self.left_key)(l);
+            self.rhs_range = match self.index {
+                Some(_) => (0, 0),
+                None => self.rhs.get_range(&key, &predicate),
+            };
+            self.current_left = Some((l, key));
+        }
+    }
+}
+
+/// The intermediate result of a [range join](JoinableByKey::range_contains_join) that will yield
+/// `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedRangeContains<'a, LIt, R, K, KF, RF, L> {
+    lhs_iter: LIt,
+
+    /// The current LHS value along with its extracted key.
+    current_left: Option<(&'a L, K)>,
+
+    rhs: &'a [R],
+
+    /// The greatest range end among RHS values up to and including each index
+    max_ends: Vec<K>,
+
+    /// The remaining candidates for the current LHS value: every range after the first is known
+    /// to start at or before the key, and every range before it ends before the key.
+    rhs_range: (usize, usize),
+    key: KF,
+    range: RF,
+}
+
+impl<'a, LIt, R, K, KF, RF, L> Iterator for JoinedRangeContains<'a, LIt, R, K, KF, RF, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    K: Ord + Clone,
+    KF: Fn(&L) -> K,
+    RF: Fn(&R) -> (K, K),
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((left, key)) = &self.current_left {
+                while self.rhs_range.0 < self.rhs_range.1 {
+                    let r = &self.rhs[self.rhs_range.0];
+                    self.rhs_range.0 += 1;
+
+                    if (self.range)(r).1 >= *key {
+                        return Some((left, r));
+                    }
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let l = self.lhs_iter.next()?;
+            let key = (self.key)(l);
+            let lower = self.max_ends.partition_point(|end| *end < key);
+            let upper = self.rhs.partition_point(|r| (self.range)(r).0 <= key);
+            self.rhs_range = (lower, upper);
+            self.current_left = Some((l, key));
+        }
+    }
+}
+
+/// The intermediate result of a [hash join](JoinableByKey::inner_join_hashed) that will yield
+/// `(&L, &R)` values.
+#[cfg(feature = "std")]
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedHashed<'a, LIt, R, K, LK, L> {
+    lhs_iter: LIt,
+
+    /// The current LHS value along with the indices of its remaining matches
+    current_left: Option<(&'a L, &'a [usize])>,
+
+    rhs: &'a HashedRHS<'a, R, K>,
+    left_key: LK,
+}
+
+#[cfg(feature = "std")]
+impl<'a, LIt, R, K, LK, L> JoinedHashed<'a, LIt, R, K, LK, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    K: Hash + Eq,
+    LK: Fn(&L) -> K,
+{
+    /// Pulls the next LHS value, returning it and whether it has any matches.
+    fn next_left(&mut self) -> Option<(&'a L, bool)> {
+        let l = self.lhs_iter.next()?;
+        let indices = self.rhs.indices(&(self.left_key)(l));
+        self.current_left = Some((l, indices));
+        Some((l, !indices.is_empty()))
+    }
+
+    /// Returns the next match of the current LHS value, if any remain.
+    fn next_match(&mut self) -> Option<(&'a L, &'a R)> {
+        let (left, indices) = self.current_left.as_mut()?;
+        let (&i, rest) = indices.split_first()?;
+        *indices = rest;
+        Some((left, self.rhs.get(i)?))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, LIt, R, K, LK, L> Iterator for JoinedHashed<'a, LIt, R, K, LK, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    K: Hash + Eq,
+    LK: Fn(&L) -> K,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pair) = self.next_match() {
+                return Some(pair);
+            }
+
+            self.next_left()?;
+        }
+    }
+}
+
+/// The intermediate result of an [outer hash join](JoinableByKey::outer_join_hashed) that will
+/// yield `(&L, Option<&R>)` values.
+#[cfg(feature = "std")]
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedHashedOuter<'a, LIt, R, K, LK, L> {
+    inner: JoinedHashed<'a, LIt, R, K, LK, L>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, LIt, R, K, LK, L> Iterator for JoinedHashedOuter<'a, LIt, R, K, LK, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    K: Hash + Eq,
+    LK: Fn(&L) -> K,
+{
+    type Item = (&'a L, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((l, r)) = self.inner.next_match() {
+            return Some((l, Some(r)));
+        }
+
+        loop {
+            let (l, matched) = self.inner.next_left()?;
+            if !matched {
+                return Some((l, None));
+            }
+
+            if let Some((l, r)) = self.inner.next_match() {
+                return Some((l, Some(r)));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a [BTreeMap join](JoinableByKey::inner_join_btreemap) that will
+/// yield `(&L, &V)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedBTreeMap<'a, LIt, K, V, LK> {
+    lhs_iter: LIt,
+    rhs: &'a BTreeMap<K, V>,
+    left_key: LK,
+}
+
+impl<'a, LIt, K, V, LK, L> Iterator for JoinedBTreeMap<'a, LIt, K, V, LK>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    K: Ord,
+    LK: Fn(&L) -> K,
+{
+    type Item = (&'a L, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rhs = self.rhs;
+        let left_key = &self.left_key;
+        self.lhs_iter
+            .find_map(|l| rhs.get(&left_key(l)).map(|v| (l, v)))
+    }
+}
+
+#[cfg(test)]
+const LEFT_ITEMS: [(usize, &str); 5] = [
+    (0, "zero"),
+    (1, "one"),
+    (2, "two"),
+    (3, "three"),
+    (4, "four"),
+];
+
+#[cfg(test)]
+const RIGHT_ITEMS: [(&str, &str); 5] = [
+    ("1", "un"),
+    ("1", "uno"),
+    ("2", "dos"),
+    ("3", "trois"),
+    ("9", "neuf"),
+];
+
+#[test]
+fn test_inner_join_by_key() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_by_key(&RIGHT_ITEMS[..], |l| l.0.to_string(), |r| r.0.to_string())
+        .map(|(l, r)| (l.1, r.1))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            ("one", "un"),
+            ("one", "uno"),
+            ("two", "dos"),
+            ("three", "trois")
+        ]
+    );
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_by_key(
+            RHS::new_sorted(&RIGHT_ITEMS),
+            |l| l.0,
+            |r| r.0.parse().unwrap(),
+        )
+        .count();
+    assert_eq!(joined, 4);
+}
+
+#[test]
+fn test_natural_inner_join() {
+    let left = [1, 2, 2, 5];
+    let right = [2, 3, 5, 5];
+
+    for rhs in [RHS::new_unsorted(&right), RHS::new_sorted(&right)] {
+        let joined = left
+            .iter()
+            .natural_inner_join(rhs)
+            .map(|(l, r)| (*l, *r))
+            .collect::<Vec<_>>();
+        assert_eq!(joined, vec![(2, 2), (2, 2), (5, 5), (5, 5)]);
+    }
+}
+
+#[test]
+fn test_inner_join_by_tuple_key() {
+    // (region, product id, quantity)
+    let sales = [
+        ("east", 1, 10),
+        ("east", 2, 5),
+        ("west", 1, 7),
+        ("west", 3, 2),
+    ];
+
+    // (region, product id, price), sorted by region then product id
+    let prices = [
+        ("east", 1, 100),
+        ("east", 2, 250),
+        ("west", 1, 110),
+        ("west", 2, 260),
+    ];
+
+    for rhs in [RHS::new_unsorted(&prices), RHS::new_sorted(&prices)] {
+        let joined = sales
+            .iter()
+            .inner_join_by_key(rhs, |l| (l.0, l.1), |r| (r.0, r.1))
+            .map(|(l, r)| (l.0, l.1, l.2 * r.2))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            joined,
+            vec![("east", 1, 1000), ("east", 2, 1250), ("west", 1, 770)]
+        );
+    }
+
+    // (year, month, day, reading) and (year, month, day, event), each sorted by date
+    let readings = [(2024, 1, 5, 3.5), (2024, 2, 1, 4.0), (2025, 1, 5, 2.5)];
+    let events = [
+        (2024, 1, 5, "thaw"),
+        (2024, 1, 6, "frost"),
+        (2025, 1, 5, "thaw"),
+    ];
+
+    for rhs in [RHS::new_unsorted(&events), RHS::new_sorted(&events)] {
+        let joined = readings
+            .iter()
+            .inner_join_by_key(rhs, |l| (l.0, l.1, l.2), |r| (r.0, r.1, r.2))
+            .map(|(l, r)| (l.3, r.3))
+            .collect::<Vec<_>>();
+        assert_eq!(joined, vec![(3.5, "thaw"), (2.5, "thaw")]);
+    }
+}
+
+#[test]
+fn test_inner_keyed() {
+    let mut joined = LEFT_ITEMS.iter().inner_join_keyed(
+        &RIGHT_ITEMS[..],
+        |l| l.0.to_string(),
+        |r| r.0.to_string(),
+        |lk, rk| lk.cmp(rk),
+    );
+
+    assert_eq!(
+        joined.next(),
+        Some((&(1, "one"), &("1", "un"), "1".to_string()))
+    );
+    assert_eq!(
+        joined.next(),
+        Some((&(1, "one"), &("1", "uno"), "1".to_string()))
+    );
+    assert_eq!(
+        joined.next(),
+        Some((&(2, "two"), &("2", "dos"), "2".to_string()))
+    );
+    assert_eq!(
+        joined.next(),
+        Some((&(3, "three"), &("3", "trois"), "3".to_string()))
+    );
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_inner_keyed_sorted() {
+    let rhs = RHS::new_sorted(&RIGHT_ITEMS);
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_keyed(rhs, |l| l.0, |r| r.0.parse().unwrap(), |lk, rk| lk.cmp(rk))
+        .map(|(_, r, k)| (k, r.1))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![(1, "un"), (1, "uno"), (2, "dos"), (3, "trois")]
+    );
+}
+
+#[test]
+fn test_inner_join_with_strategy() {
+    let right = [(1, "un"), (1, "uno"), (2, "dos"), (3, "trois"), (9, "neuf")];
+
+    let strategies = [
+        JoinStrategy::LinearScan,
+        JoinStrategy::BinarySearch,
+        JoinStrategy::HashLookup,
+    ];
+    for strategy in strategies {
+        let joined = LEFT_ITEMS
+            .iter()
+            .inner_join_with_strategy(&right, |l| l.0, |r| r.0, strategy)
+            .map(|(l, r)| (l.1, r.1))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            joined,
+            vec![
+                ("one", "un"),
+                ("one", "uno"),
+                ("two", "dos"),
+                ("three", "trois")
+            ],
+            "{strategy:?}"
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "requires RHS sorted")]
+fn test_inner_join_with_strategy_unsorted() {
+    let right = [(3, "trois"), (1, "un")];
+    let _ = LEFT_ITEMS.iter().inner_join_with_strategy(
+        &right,
+        |l| l.0,
+        |r| r.0,
+        JoinStrategy::BinarySearch,
+    );
+}
+
+#[test]
+fn test_range_contains_join() {
+    // (first address, last address, location)
+    let ranges = [
+        (0, 9, "a"),
+        (10, 19, "b"),
+        (15, 30, "c"),
+        (20, 25, "d"),
+        (40, 49, "e"),
+    ];
+
+    let lookup = |ips: &[u32]| {
+        ips.iter()
+            .range_contains_join(&ranges, |ip| *ip, |r| (r.0, r.1))
+            .map(|(ip, r)| (*ip, r.2))
+            .collect::<Vec<_>>()
+    };
+
+    // Inside one range, including its endpoints
+    assert_eq!(lookup(&[5, 10, 49]), vec![(5, "a"), (10, "b"), (49, "e")]);
+
+    // Inside overlapping ranges
+    assert_eq!(
+        lookup(&[17, 22]),
+        vec![(17, "b"), (17, "c"), (22, "c"), (22, "d")]
+    );
+
+    // In a gap, or beyond every range
+    assert!(lookup(&[35, 50]).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hashed() {
+    let rhs = HashedRHS::new(&RIGHT_ITEMS, |r| r.0.parse::<usize>().unwrap());
+    assert_eq!(rhs.len(), RIGHT_ITEMS.len());
+
+    let inner = LEFT_ITEMS
+        .iter()
+        .inner_join_hashed(&rhs, |l| l.0)
+        .map(|(l, r)| (l.1, r.1))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        inner,
+        vec![
+            ("one", "un"),
+            ("one", "uno"),
+            ("two", "dos"),
+            ("three", "trois")
+        ]
+    );
+
+    let outer = LEFT_ITEMS
+        .iter()
+        .outer_join_hashed(&rhs, |l| l.0)
+        .map(|(l, r)| (l.1, r.map(|r| r.1)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outer,
+        vec![
+            ("zero", None),
+            ("one", Some("un")),
+            ("one", Some("uno")),
+            ("two", Some("dos")),
+            ("three", Some("trois")),
+            ("four", None),
+        ]
+    );
+}
+
+#[test]
+fn test_btreemap() {
+    let mut rhs = BTreeMap::new();
+    for r in RIGHT_ITEMS.iter() {
+        let key = r.0.parse::<usize>().unwrap();
+        rhs.entry(key).or_insert_with(Vec::new).push(r.1);
+    }
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_btreemap(&rhs, |l| l.0)
+        .map(|(l, rs)| (l.1, rs.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        joined,
+        vec![
+            ("one", vec!["un", "uno"]),
+            ("two", vec!["dos"]),
+            ("three", vec!["trois"])
+        ]
+    );
+}
@@ -1,4 +1,16 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::collector::Collector;
 use crate::rhs::RHS;
+#[cfg(feature = "serde")]
+use crate::serialize::SerializableRows;
 
 enum JoinType {
     Inner,
@@ -12,10 +24,13 @@ enum JoinType {
 /// Results for [inner_join_grouped](JoinableGrouped::inner_join_grouped) and
 /// [outer_join_grouped](JoinableGrouped::outer_join_grouped) are individual LHS records and a
 /// `Vec<R>`, which can be empty for outer joins if no match is found.
+///
+/// This is implemented for anything that can be iterated over as `L`, so a `Vec` or array can be
+/// joined directly, consuming it, as well as its iterators.
 pub trait JoinableGrouped<'a, LIt, R, P, L> {
     /// Joins LHS and RHS, keeping only records from left that have one or more matches in right.
     ///
-    /// The specified predicate returns a [std::cmp::Ordering] comparing left and right records.
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
     ///
     /// Like `outer_join_grouped`, this function returns a `(L, Vec<&R>)` with matching records from
     /// RHS being collected. If multiple records from left match a given record from right,
@@ -28,7 +43,7 @@ pub trait JoinableGrouped<'a, LIt, R, P, L> {
 
     /// Joins LHS and RHS, keeping _all_ records from left.
     ///
-    /// The specified predicate returns a [std::cmp::Ordering] comparing left and right records.
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
     ///
     /// Like `inner_join_grouped`, this function returns a `(L, Vec<&R>)` with matching records from
     /// RHS being collected. If multiple records from left match a given record from right,
@@ -37,29 +52,510 @@ pub trait JoinableGrouped<'a, LIt, R, P, L> {
         self,
         rhs: impl Into<RHS<'a, R>>,
         predicate: P,
-    ) -> JoinedGrouped<'a, LIt, R, P>;
+    ) -> JoinedGroupedOuter<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS as [outer_join_grouped](JoinableGrouped::outer_join_grouped) does, but
+    /// keeps each distinct match of a left record only once.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Matches are distinct according to `eq`, such as `|a, b| a == b` for `R: PartialEq`.
+    /// Deduplication is only within each left record's group: the same right record can still
+    /// appear in the groups of several left records. Of equal matches, the first in RHS order is
+    /// kept, whether or not they are adjacent, so sorted and unsorted RHS give the same groups.
+    /// Each match is compared against those already kept for the group, as with
+    /// [dedup_join](crate::Joinable::dedup_join).
+    fn outer_join_grouped_dedup<E>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        eq: E,
+    ) -> JoinedGroupedDedup<'a, LIt, R, P, E>
+    where
+        E: Fn(&R, &R) -> bool;
 
     /// Joins LHS and RHS, keeping all records from left that have one or more matches in right.
     ///
-    /// The specified predicate returns a [std::cmp::Ordering] comparing left and right records.
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
     ///
     /// Like `anti_join`, this function only returns left records.
     fn semi_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedLeft<'a, LIt, R, P>;
 
     /// Joins LHS and RHS, keeping all records from left that have _no_ matches in right.
     ///
-    /// The specified predicate returns a [std::cmp::Ordering] comparing left and right records.
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
     ///
     /// Like `semi_join`, this function only returns left records.
     fn anti_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedLeft<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, keeping all records from left that have _no_ matches in right, in the
+    /// same `(L, Vec<&R>)` shape as `outer_join_grouped`.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// The `Vec` is always empty, and is never allocated; as with `anti_join`, RHS is searched
+    /// only until a match is found.
+    fn anti_join_grouped(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedGrouped<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, keeping _all_ records from left and reducing each left record's matches
+    /// to a single representative right record.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Matches are folded pairwise with `reduce`, which picks one of the two references (eg, the
+    /// order with the larger amount). This yields `(L, Option<&R>)`, with `None` when a left record
+    /// has no match. Unlike `inner_join_grouped`, no `Vec` is built for each group.
+    fn inner_join_reduce<F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        reduce: F,
+    ) -> JoinedReduce<'a, LIt, R, P, F>
+    where
+        F: Fn(&'a R, &'a R) -> &'a R;
+
+    /// Joins LHS and RHS, calling `f` with each record from left that has one or more matches in
+    /// right, reusing `buffer` to hold the matches instead of allocating a `Vec` per record.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// `buffer` is cleared before each left record is searched, so the `&[&R]` handed to `f` only
+    /// borrows the buffer for the duration of that call. The `&R` values themselves borrow RHS and
+    /// can be copied out if they're needed later. Once this returns, `buffer` keeps its capacity
+    /// for the next join.
+    fn inner_join_grouped_for_each<F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        buffer: &mut Vec<&'a R>,
+        f: F,
+    ) where
+        F: FnMut(L, &[&'a R]);
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// and splits each record's matches in two.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, Vec<&R>, Vec<&R>)`, where the first `Vec` holds the matches
+    /// for which `split` returned `true` and the second holds the rest. Either may be empty, but
+    /// not both.
+    fn inner_join_grouped_partition<S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        split: S,
+    ) -> JoinedPartitioned<'a, LIt, R, P, S>
+    where
+        S: Fn(&R) -> bool;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right
+    /// and transforming each match with `f`.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, Vec<T>)` for each record in left that has matches, which is
+    /// equivalent to mapping each group from
+    /// [inner_join_grouped](JoinableGrouped::inner_join_grouped) but without the intermediate
+    /// `Vec<&R>`.
+    fn inner_join_grouped_map<F, T>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        f: F,
+    ) -> JoinedGroupedMap<'a, LIt, R, P, F>
+    where
+        F: Fn(&R) -> T;
+
+    /// Joins LHS and RHS as [inner_join_grouped](JoinableGrouped::inner_join_grouped) does, but
+    /// drops right records that are identical to an earlier match of the same left record.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This handles RHS containing literal duplicate records, not just records with equal keys.
+    /// The first of each set of duplicates is kept. Each match is compared against the group's
+    /// earlier matches, so this is quadratic in the size of each group.
+    fn inner_join_grouped_unique(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedGroupedUnique<'a, LIt, R, P>
+    where
+        R: PartialEq;
+
+    /// Joins LHS and RHS, yielding each record from left along with its number of matches in
+    /// right, without collecting the matches themselves.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This yields `(L, usize)` for every record in left, in order, including those with no
+    /// matches. For sorted RHS, matches are counted without being visited. The flat stream of
+    /// matches aligned with these counts is [inner_join](crate::Joinable::inner_join) over the
+    /// same LHS and RHS with the same comparison: after pulling `(l, n)` here, the next `n` pairs
+    /// pulled from it belong to `l`, in RHS order. The caller decides where matches are stored,
+    /// and nothing is buffered between the two.
+    fn inner_join_boundaries(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedBoundaries<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// and collecting at most `cap` matches per left record.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, Vec<&R>)` for each record in left that has matches, where the
+    /// `Vec` holds its first `cap` matches. Any further matches are passed to `overflow` instead,
+    /// so that a single very common key can't exhaust memory but its extra matches can still be
+    /// accounted for. `overflow` is called before the group is yielded.
+    fn inner_join_grouped_capped<O>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        cap: usize,
+        overflow: O,
+    ) -> JoinedGroupedCapped<'a, LIt, R, P, O>
+    where
+        O: FnMut(&L, &R);
+
+    /// Joins LHS and RHS as [inner_join_grouped](JoinableGrouped::inner_join_grouped) does, but
+    /// collects at most the first `n` matches of each left record.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// The search for each left record stops as soon as `n` matches are found, whether or not RHS
+    /// is sorted, so large groups are never materialized. An unsorted RHS is still scanned up to
+    /// the `n`th match, or entirely if there are fewer. If `n` is zero, nothing is yielded.
+    fn inner_join_grouped_limited(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        n: usize,
+    ) -> JoinedGroupedLimited<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// along with an aggregate of those matches.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, Vec<&R>, A)` for each record in left that has matches, where
+    /// `A` is computed by `agg` from the full group of matches (eg, a count and sum).
+    fn inner_join_grouped_with_agg<F, A>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        agg: F,
+    ) -> JoinedGroupedAgg<'a, LIt, R, P, F>
+    where
+        F: Fn(&[&'a R]) -> A;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// and ranks each record's matches by weight.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, Vec<(&R, f64)>)` for each record in left that has matches,
+    /// where each match is paired with its `weight` and the matches are sorted by descending
+    /// weight. Matches with equal weights keep their RHS order.
+    fn weighted_join<W>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        weight: W,
+    ) -> JoinedWeighted<'a, LIt, R, P, W>
+    where
+        W: Fn(&L, &R) -> f64;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// with the matches grouped by category.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, HashMap<C, Vec<&R>>)` for each record in left that has
+    /// matches, where each match is grouped under the category returned for it by `category`.
+    /// Matches within a category keep their RHS order. See
+    /// [inner_join_categorized_ordered](JoinableGrouped::inner_join_categorized_ordered) for
+    /// deterministically ordered categories.
+    #[cfg(feature = "std")]
+    fn inner_join_categorized<F, C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        category: F,
+    ) -> JoinedCategorized<'a, LIt, R, P, F, HashMap<C, Vec<&'a R>>>
+    where
+        F: Fn(&R) -> C,
+        C: Hash + Eq;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// with the matches grouped by category into a `BTreeMap` so that categories are ordered.
+    ///
+    /// Unlike `inner_join_categorized`, this is available without the `std` feature.
+    fn inner_join_categorized_ordered<F, C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        category: F,
+    ) -> JoinedCategorized<'a, LIt, R, P, F, BTreeMap<C, Vec<&'a R>>>
+    where
+        F: Fn(&R) -> C,
+        C: Ord;
+
+    /// Joins LHS against two right-hand sides at once, keeping only records from left that have
+    /// one or more matches in either.
+    ///
+    /// Each predicate returns a [core::cmp::Ordering] comparing left records with records from its
+    /// right-hand side. This function returns a `(L, Vec<&R>, Vec<&RB>)` for each record in left
+    /// with any matches, holding its matches from `rhs_a` and `rhs_b` respectively; either may be
+    /// empty, but not both.
+    fn inner_join_two_sources<RB, PB>(
+        self,
+        rhs_a: impl Into<RHS<'a, R>>,
+        rhs_b: impl Into<RHS<'a, RB>>,
+        predicate_a: P,
+        predicate_b: PB,
+    ) -> JoinedTwoSources<'a, LIt, R, RB, P, PB>
+    where
+        RB: 'a,
+        PB: Fn(&L, &RB) -> core::cmp::Ordering;
+
+    /// Joins LHS and RHS as [inner_join_grouped](JoinableGrouped::inner_join_grouped) does, while
+    /// checking in debug builds that each group is an equivalence class.
+    ///
+    /// Grouping relies on the predicate's [Equal](core::cmp::Ordering::Equal) being transitive.
+    /// A predicate can break this by finding one left record equal to right records that belong
+    /// to different logical keys. In debug builds, every member of each group is compared to the
+    /// first with `right_cmp`, which compares right records by the same key as `predicate`. In
+    /// release builds, `right_cmp` is never called.
+    ///
+    /// # Panics
+    /// Panics in debug builds if two members of a group don't compare equal, giving their indices
+    /// in RHS.
+    fn grouped_join_checked<C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        right_cmp: C,
+    ) -> JoinedGroupedChecked<'a, LIt, R, P, C>
+    where
+        C: Fn(&R, &R) -> core::cmp::Ordering;
+
+    /// Joins LHS and RHS as [inner_join_grouped](JoinableGrouped::inner_join_grouped) does, then
+    /// sorts the results by the number of matches in each group.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Because sorting requires seeing every group, this is not lazy: the entire join is
+    /// materialized into the returned `Vec`. Groups of equal size keep their LHS order.
+    fn collect_grouped_by_size(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        descending: bool,
+    ) -> Vec<(L, Vec<&'a R>)>;
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// and collects the results into a compressed sparse row (CSR) layout.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Returns `(lefts, offsets, rights)`: the matched left records in LHS order, the matches of
+    /// every left record flattened into `rights`, and `offsets`, which has one more entry than
+    /// `lefts` such that the matches of `lefts[i]` are `rights[offsets[i]..offsets[i + 1]]`.
+    fn collect_grouped_csr(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (Vec<L>, Vec<usize>, Vec<&'a R>);
+
+    /// Joins LHS and RHS into a pivot table, aggregating the matches for each combination of left
+    /// key and right category.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Each match is bucketed under `(left_key(l), right_cat(r))`; left records sharing a key
+    /// share buckets. Each bucket is then reduced with `agg`, which receives its matches in the
+    /// order they were found. Only combinations with at least one match are present.
+    #[cfg(feature = "std")]
+    fn pivot_join<KF, LK, CF, RC, AF, V>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        left_key: KF,
+        right_cat: CF,
+        agg: AF,
+    ) -> HashMap<(LK, RC), V>
+    where
+        R: 'a,
+        KF: Fn(&L) -> LK,
+        LK: Hash + Eq + Clone,
+        CF: Fn(&R) -> RC,
+        RC: Hash + Eq,
+        AF: Fn(&[&R]) -> V;
+
+    /// Returns the index range of the longest run of consecutive records from left that all have
+    /// one or more matches in right, such as for checking how well a sorted LHS is covered.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// If several runs are equally long, the first is returned. If no records match, the range is
+    /// empty.
+    fn longest_matched_run(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> core::ops::Range<usize>
+    where
+        R: 'a;
+
+    /// Counts the matches in right of each record from left, returning how many records had each
+    /// number of matches, such as for profiling a join's fan-out before running it.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// The returned map goes from a number of matches to the number of left records with that
+    /// many, including those with none. Counts that no record had are absent.
+    fn match_histogram(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> BTreeMap<usize, usize>
+    where
+        R: 'a;
+
+    /// Joins LHS and RHS, counting the matches in right of each record from left rather than
+    /// collecting them.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns one `(L, usize)` per left record, including those with no matches.
+    /// For [sorted](RHS::Sorted) RHS, the count is the width of the matching range, so no matches
+    /// are visited; otherwise, RHS is scanned once per left record. Either way, no `Vec` is
+    /// allocated as it would be by [outer_join_grouped](JoinableGrouped::outer_join_grouped).
+    fn match_count_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedMatchCount<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, marking each record from left with whether it has one or more matches
+    /// in right.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns one `(L, bool)` per left record, as SQL's `EXISTS` would as a
+    /// column. Unlike running both `semi_join` and `anti_join`, LHS is only iterated once. The
+    /// search for each left record stops at its first match.
+    fn mark_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedMark<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, keeping all records from left that have one or more matches in right,
+    /// along with their first match.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This function returns a `(L, &R)` for each record in left that has matches, without
+    /// allocating a `Vec` of them as [inner_join_grouped](JoinableGrouped::inner_join_grouped)
+    /// would. The match is the first in RHS order: the earliest in the slice for
+    /// [unsorted](RHS::Unsorted) RHS, and the first of the equal range for [sorted](RHS::Sorted)
+    /// RHS. For a [partially sorted](RHS::PartiallySorted) RHS, whose sort is unstable, it is
+    /// whichever match sorts first.
+    fn semi_join_first(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedSemiFirst<'a, LIt, R, P>;
+
+    /// Joins LHS and RHS, collapsing consecutive left records with equal keys into one group that
+    /// shares a single search of RHS.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records,
+    /// and must give the same result for any left records with the same `key`.
+    ///
+    /// This function returns a `(Vec<L>, Vec<&R>)` for each run of left records with equal keys
+    /// that has one or more matches, in LHS order. Only adjacent records are collapsed, so LHS
+    /// should be sorted (or at least grouped) by key; then RHS is searched once per distinct key
+    /// rather than once per left record.
+    fn coalesced_inner_join<K, F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        key: F,
+    ) -> JoinedCoalesced<'a, LIt, R, P, F, L>
+    where
+        K: PartialEq,
+        F: Fn(&L) -> K;
+
+    /// Joins LHS and RHS in a single pass, splitting left into the records that have one or more
+    /// matches in right and those that have none.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// This is equivalent to running both [inner_join_grouped](JoinableGrouped::inner_join_grouped)
+    /// and [anti_join](JoinableGrouped::anti_join), but searches RHS only once per left record.
+    /// Each record from left is moved into exactly one of the returned `Vec`s: matched records
+    /// along with their matches, or unmatched records on their own. Both keep LHS order.
+    fn split_matched(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (Vec<(L, Vec<&'a R>)>, Vec<L>);
+
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right,
+    /// and marks which right records were matched.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Along with the grouped matches, returns a mask with one entry per RHS record, in RHS order,
+    /// that is `true` if the record matched at least one left record. Iterating RHS alongside the
+    /// mask gives the unmatched right records, eg to build a custom full outer join.
+    fn inner_join_with_right_mask(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (Vec<(L, Vec<&'a R>)>, Vec<bool>);
+
+    /// Returns the first `n` records from left that have one or more matches in right, along
+    /// with their matches, such as for previewing a join.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Unlike taking `n` results of [outer_join_grouped](JoinableGrouped::outer_join_grouped),
+    /// only matched records count towards `n`. LHS is not consumed any further than needed to
+    /// find them.
+    fn sample_matched(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        n: usize,
+    ) -> Vec<(L, Vec<&'a R>)>;
+
+    /// Joins a sorted LHS and RHS, collecting consecutive left records into sessions.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// A left record continues the current session if any of its matches is also a match of the
+    /// session so far, or if `same_session` returns `true` when given the session's last left
+    /// record and this one (eg, because their timestamps are within some gap). Otherwise, it
+    /// starts a new session. Each session is yielded as `(Vec<L>, Vec<&R>)`, where the right
+    /// records are the union of its members' matches in the order they were first seen.
+    fn session_join<G>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        same_session: G,
+    ) -> JoinedSessions<'a, LIt, R, P, G, L>
+    where
+        G: Fn(&L, &L) -> bool;
 }
 
-impl<'a, LIt, R, P, L> JoinableGrouped<'a, LIt, R, P, L> for LIt
+impl<'a, I, LIt, R, P, L> JoinableGrouped<'a, LIt, R, P, L> for I
 where
+    I: IntoIterator<Item = L, IntoIter = LIt>,
     LIt: Iterator<Item = L>,
     L: 'a,
     R: 'a,
-    P: Fn(&L, &R) -> std::cmp::Ordering,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
 {
     fn inner_join_grouped(
         self,
@@ -67,7 +563,7 @@ where
         predicate: P,
     ) -> JoinedGrouped<'a, LIt, R, P> {
         JoinedGrouped {
-            lhs_iter: self,
+            lhs_iter: self.into_iter(),
             rhs: rhs.into(),
             predicate,
             join_type: crate::joined_grouped::JoinType::Inner,
@@ -78,18 +574,35 @@ where
         self,
         rhs: impl Into<RHS<'a, R>>,
         predicate: P,
-    ) -> JoinedGrouped<'a, LIt, R, P> {
-        JoinedGrouped {
-            lhs_iter: self,
-            rhs: rhs.into(),
-            predicate,
-            join_type: crate::joined_grouped::JoinType::Outer,
+    ) -> JoinedGroupedOuter<'a, LIt, R, P> {
+        JoinedGroupedOuter {
+            inner: JoinedGrouped {
+                lhs_iter: self.into_iter(),
+                rhs: rhs.into(),
+                predicate,
+                join_type: crate::joined_grouped::JoinType::Outer,
+            },
+        }
+    }
+
+    fn outer_join_grouped_dedup<E>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        eq: E,
+    ) -> JoinedGroupedDedup<'a, LIt, R, P, E>
+    where
+        E: Fn(&R, &R) -> bool,
+    {
+        JoinedGroupedDedup {
+            inner: self.outer_join_grouped(rhs, predicate),
+            eq,
         }
     }
 
     fn semi_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedLeft<'a, LIt, R, P> {
         JoinedLeft {
-            lhs_iter: self,
+            lhs_iter: self.into_iter(),
             rhs: rhs.into(),
             predicate,
             join_type: crate::joined_grouped::JoinType::Semi,
@@ -98,237 +611,2690 @@ where
 
     fn anti_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedLeft<'a, LIt, R, P> {
         JoinedLeft {
-            lhs_iter: self,
+            lhs_iter: self.into_iter(),
             rhs: rhs.into(),
             predicate,
             join_type: crate::joined_grouped::JoinType::Anti,
         }
     }
-}
-
-/// The intermediate result of an inner- or outer-join that will yield `(L, Vec<&R>)` values.
-pub struct JoinedGrouped<'a, LIt, R, P> {
-    /// The iterator over all left-hand side values
-    lhs_iter: LIt,
 
-    /// A value giving us access to all right-hand side values
-    rhs: RHS<'a, R>,
-
-    /// A comparison predicate: Fn(&L, &R) -> std::cmp::Ordering
-    predicate: P,
+    fn anti_join_grouped(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedGrouped<'a, LIt, R, P> {
+        JoinedGrouped {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            join_type: crate::joined_grouped::JoinType::Anti,
+        }
+    }
 
-    /// One of: Inner, Outer, Semi, Anti
-    join_type: JoinType,
-}
+    fn inner_join_reduce<F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        reduce: F,
+    ) -> JoinedReduce<'a, LIt, R, P, F>
+    where
+        F: Fn(&'a R, &'a R) -> &'a R,
+    {
+        JoinedReduce {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            reduce,
+        }
+    }
 
-impl<'a, LIt, R, P, L> Iterator for JoinedGrouped<'a, LIt, R, P>
-where
-    LIt: Iterator<Item = L>,
-    L: 'a,
-    R: 'a,
-    P: Fn(&L, &R) -> std::cmp::Ordering,
-{
-    type Item = (L, Vec<&'a R>);
+    fn inner_join_grouped_for_each<F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        buffer: &mut Vec<&'a R>,
+        mut f: F,
+    ) where
+        F: FnMut(L, &[&'a R]),
+    {
+        let rhs = rhs.into();
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let left = self.lhs_iter.next()?;
+        for left in self {
+            buffer.clear();
+            rhs.push_matches(&left, &predicate, buffer);
 
-            let rs = match self.rhs {
-                RHS::Unsorted(inner) => inner
-                    .iter()
-                    .filter(|r| (self.predicate)(&left, r).is_eq())
-                    .collect::<Vec<_>>(),
-                RHS::Sorted(inner) => {
-                    match inner.binary_search_by(|r| (self.predicate)(&left, r)) {
-                        Ok(mut pos) => {
-                            let mut rs = Vec::new();
-                            // We found *a* match, but it may not be the first one
-                            while pos > 0 && (self.predicate)(&left, &inner[pos - 1]).is_eq() {
-                                pos -= 1;
-                            }
-
-                            // Found the first; now add every one in order until we reach a different one or the end
-                            while pos < inner.len() && (self.predicate)(&left, &inner[pos]).is_eq()
-                            {
-                                rs.push(&inner[pos]);
-                                pos += 1;
-                            }
-
-                            rs
-                        }
-                        Err(_) => vec![],
-                    }
-                }
-            };
+            if !buffer.is_empty() {
+                f(left, buffer);
+            }
+        }
+    }
 
-            match self.join_type {
-                JoinType::Inner => {
-                    if !rs.is_empty() {
-                        return Some((left, rs));
-                    }
-                }
+    fn inner_join_grouped_partition<S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        split: S,
+    ) -> JoinedPartitioned<'a, LIt, R, P, S>
+    where
+        S: Fn(&R) -> bool,
+    {
+        JoinedPartitioned {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            split,
+        }
+    }
 
-                JoinType::Outer => return Some((left, rs)),
+    fn inner_join_grouped_map<F, T>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        f: F,
+    ) -> JoinedGroupedMap<'a, LIt, R, P, F>
+    where
+        F: Fn(&R) -> T,
+    {
+        JoinedGroupedMap {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            f,
+        }
+    }
 
-                JoinType::Semi => unreachable!(),
-                JoinType::Anti => unreachable!(),
-            }
+    fn inner_join_grouped_unique(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedGroupedUnique<'a, LIt, R, P>
+    where
+        R: PartialEq,
+    {
+        JoinedGroupedUnique {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
         }
     }
-}
 
-/// The intermediate result of a semi- or anti-join that will yield `L` values.
-pub struct JoinedLeft<'a, LIt, R, P> {
-    lhs_iter: LIt,
-    rhs: RHS<'a, R>,
-    predicate: P,
+    fn inner_join_boundaries(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedBoundaries<'a, LIt, R, P> {
+        JoinedBoundaries {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+        }
+    }
+
+    fn inner_join_grouped_limited(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        n: usize,
+    ) -> JoinedGroupedLimited<'a, LIt, R, P> {
+        JoinedGroupedLimited {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            n,
+        }
+    }
+
+    fn inner_join_grouped_capped<O>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        cap: usize,
+        overflow: O,
+    ) -> JoinedGroupedCapped<'a, LIt, R, P, O>
+    where
+        O: FnMut(&L, &R),
+    {
+        JoinedGroupedCapped {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            cap,
+            overflow,
+        }
+    }
+
+    fn inner_join_grouped_with_agg<F, A>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        agg: F,
+    ) -> JoinedGroupedAgg<'a, LIt, R, P, F>
+    where
+        F: Fn(&[&'a R]) -> A,
+    {
+        JoinedGroupedAgg {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            agg,
+        }
+    }
+
+    fn weighted_join<W>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        weight: W,
+    ) -> JoinedWeighted<'a, LIt, R, P, W>
+    where
+        W: Fn(&L, &R) -> f64,
+    {
+        JoinedWeighted {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            weight,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn inner_join_categorized<F, C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        category: F,
+    ) -> JoinedCategorized<'a, LIt, R, P, F, HashMap<C, Vec<&'a R>>>
+    where
+        F: Fn(&R) -> C,
+        C: Hash + Eq,
+    {
+        JoinedCategorized {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            category,
+            categories: PhantomData,
+        }
+    }
+
+    fn inner_join_categorized_ordered<F, C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        category: F,
+    ) -> JoinedCategorized<'a, LIt, R, P, F, BTreeMap<C, Vec<&'a R>>>
+    where
+        F: Fn(&R) -> C,
+        C: Ord,
+    {
+        JoinedCategorized {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            category,
+            categories: PhantomData,
+        }
+    }
+
+    fn grouped_join_checked<C>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        right_cmp: C,
+    ) -> JoinedGroupedChecked<'a, LIt, R, P, C>
+    where
+        C: Fn(&R, &R) -> core::cmp::Ordering,
+    {
+        JoinedGroupedChecked {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            right_cmp,
+        }
+    }
+
+    fn inner_join_two_sources<RB, PB>(
+        self,
+        rhs_a: impl Into<RHS<'a, R>>,
+        rhs_b: impl Into<RHS<'a, RB>>,
+        predicate_a: P,
+        predicate_b: PB,
+    ) -> JoinedTwoSources<'a, LIt, R, RB, P, PB>
+    where
+        RB: 'a,
+        PB: Fn(&L, &RB) -> core::cmp::Ordering,
+    {
+        JoinedTwoSources {
+            lhs_iter: self.into_iter(),
+            rhs_a: rhs_a.into(),
+            rhs_b: rhs_b.into(),
+            predicate_a,
+            predicate_b,
+        }
+    }
+
+    fn collect_grouped_by_size(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        descending: bool,
+    ) -> Vec<(L, Vec<&'a R>)> {
+        let mut groups = self.inner_join_grouped(rhs, predicate).collect::<Vec<_>>();
+
+        if descending {
+            groups.sort_by_key(|g| core::cmp::Reverse(g.1.len()));
+        } else {
+            groups.sort_by_key(|g| g.1.len());
+        }
+
+        groups
+    }
+
+    fn collect_grouped_csr(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (Vec<L>, Vec<usize>, Vec<&'a R>) {
+        let rhs = rhs.into();
+        let (mut lefts, mut offsets, mut rights) = (Vec::new(), vec![0], Vec::new());
+
+        for left in self {
+            rhs.push_matches(&left, &predicate, &mut rights);
+
+            if rights.len() > offsets[offsets.len() - 1] {
+                lefts.push(left);
+                offsets.push(rights.len());
+            }
+        }
+
+        (lefts, offsets, rights)
+    }
+
+    #[cfg(feature = "std")]
+    fn pivot_join<KF, LK, CF, RC, AF, V>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        left_key: KF,
+        right_cat: CF,
+        agg: AF,
+    ) -> HashMap<(LK, RC), V>
+    where
+        R: 'a,
+        KF: Fn(&L) -> LK,
+        LK: Hash + Eq + Clone,
+        CF: Fn(&R) -> RC,
+        RC: Hash + Eq,
+        AF: Fn(&[&R]) -> V,
+    {
+        let rhs = rhs.into();
+        let mut buckets: HashMap<(LK, RC), Vec<&'a R>> = HashMap::new();
+
+        for left in self {
+            let key = left_key(&left);
+            let mut range = rhs.get_range(&left, &predicate);
+            while let Some((_, r)) = rhs.next_match(&left, &predicate, &mut range) {
+                buckets
+                    .entry((key.clone(), right_cat(r)))
+                    .or_default()
+                    .push(r);
+            }
+        }
+
+        buckets.into_iter().map(|(k, rs)| (k, agg(&rs))).collect()
+    }
+
+    fn longest_matched_run(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> core::ops::Range<usize>
+    where
+        R: 'a,
+    {
+        let rhs = rhs.into();
+        let mut longest = 0..0;
+        let mut run_start = 0;
+
+        for (i, left) in self.into_iter().enumerate() {
+            if !rhs.has_value(&left, &predicate) {
+                run_start = i + 1;
+            } else if i + 1 - run_start > longest.len() {
+                longest = run_start..i + 1;
+            }
+        }
+
+        longest
+    }
+
+    fn match_histogram(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> BTreeMap<usize, usize>
+    where
+        R: 'a,
+    {
+        let rhs = rhs.into();
+        let mut histogram = BTreeMap::new();
+
+        for left in self {
+            let count = rhs.count_matches(&left, &predicate);
+            *histogram.entry(count).or_insert(0) += 1;
+        }
+
+        histogram
+    }
+
+    fn match_count_join(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedMatchCount<'a, LIt, R, P> {
+        JoinedMatchCount {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+        }
+    }
+
+    fn mark_join(self, rhs: impl Into<RHS<'a, R>>, predicate: P) -> JoinedMark<'a, LIt, R, P> {
+        JoinedMark {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+        }
+    }
+
+    fn semi_join_first(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedSemiFirst<'a, LIt, R, P> {
+        JoinedSemiFirst {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+        }
+    }
+
+    fn coalesced_inner_join<K, F>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        key: F,
+    ) -> JoinedCoalesced<'a, LIt, R, P, F, L>
+    where
+        K: PartialEq,
+        F: Fn(&L) -> K,
+    {
+        JoinedCoalesced {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            key,
+            next_left: None,
+        }
+    }
+
+    fn split_matched(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (Vec<(L, Vec<&'a R>)>, Vec<L>) {
+        let rhs = rhs.into();
+        let (mut matched, mut unmatched) = (Vec::new(), Vec::new());
+
+        for left in self {
+            let mut rs = Vec::new();
+            rhs.push_matches(&left, &predicate, &mut rs);
+
+            if rs.is_empty() {
+                unmatched.push(left);
+            } else {
+                matched.push((left, rs));
+            }
+        }
+
+        (matched, unmatched)
+    }
+
+    fn inner_join_with_right_mask(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> (Vec<(L, Vec<&'a R>)>, Vec<bool>) {
+        let rhs = rhs.into();
+        let mut mask = vec![false; rhs.len()];
+        let mut matched = Vec::new();
+
+        for left in self {
+            let mut range = rhs.get_range(&left, &predicate);
+            let mut rs = Vec::new();
+            while let Some((i, r)) = rhs.next_match(&left, &predicate, &mut range) {
+                mask[rhs.original_index(i)] = true;
+                rs.push(r);
+            }
+
+            if !rs.is_empty() {
+                matched.push((left, rs));
+            }
+        }
+
+        (matched, mask)
+    }
+
+    fn sample_matched(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        n: usize,
+    ) -> Vec<(L, Vec<&'a R>)> {
+        self.inner_join_grouped(rhs, predicate).take(n).collect()
+    }
+
+    fn session_join<G>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        same_session: G,
+    ) -> JoinedSessions<'a, LIt, R, P, G, L>
+    where
+        G: Fn(&L, &L) -> bool,
+    {
+        JoinedSessions {
+            lhs_iter: self.into_iter(),
+            rhs: rhs.into(),
+            predicate,
+            same_session,
+            pending: None,
+        }
+    }
+}
+
+/// Adds the methods that drain or adapt a grouped join as a whole to each iterator yielding
+/// `(L, Vec<&R>)` for every LHS value.
+macro_rules! grouped_terminal_methods {
+    ($joined:ident) => {
+        impl<'a, LIt, R, P> $joined<'a, LIt, R, P>
+        where
+            Self: Iterator,
+        {
+            /// Clears `out` and fills it with every remaining result, reusing its existing
+            /// allocation.
+            pub fn collect_into(self, out: &mut Vec<<Self as Iterator>::Item>) {
+                out.clear();
+                out.extend(self);
+            }
+
+            /// Pushes every remaining result into `collector`, which is returned. Pass
+            /// `&mut collector` to keep ownership of it.
+            pub fn collect_with<C>(self, collector: C) -> C
+            where
+                C: Collector<<Self as Iterator>::Item>,
+            {
+                crate::collector::collect_with(self, collector)
+            }
+
+            /// Drains the join into a map from the key of each left record to its matches.
+            ///
+            /// Left records with equal keys have their matches combined into one `Vec`, in the
+            /// order they were yielded.
+            #[cfg(feature = "std")]
+            pub fn into_hashmap<L, K, F>(self, key: F) -> HashMap<K, Vec<&'a R>>
+            where
+                Self: Iterator<Item = (L, Vec<&'a R>)>,
+                K: Hash + Eq,
+                F: Fn(&L) -> K,
+            {
+                let mut map = HashMap::new();
+                for (left, rs) in self {
+                    map.entry(key(&left)).or_insert_with(Vec::new).extend(rs);
+                }
+                map
+            }
+
+            /// Yields only the left record of each result, dropping its matches.
+            ///
+            /// Runs of equal left records are yielded once, so over a sorted LHS each distinct
+            /// record is yielded once. For an inner join, these are the records from left that had
+            /// one or more matches.
+            pub fn left_keys<L>(self) -> LeftKeys<Self, L>
+            where
+                Self: Iterator<Item = (L, Vec<&'a R>)>,
+                L: PartialEq,
+            {
+                LeftKeys {
+                    inner: self,
+                    pending: None,
+                }
+            }
+
+            /// Yields each result as a [GroupedRow](crate::GroupedRow), which can be serialized,
+            /// such as to write the whole join out as JSON.
+            #[cfg(feature = "serde")]
+            pub fn serializable(self) -> SerializableRows<Self> {
+                SerializableRows { inner: self }
+            }
+        }
+    };
+}
+
+/// The intermediate result of an inner- or outer-join that will yield `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGrouped<'a, LIt, R, P> {
+    /// The iterator over all left-hand side values
+    lhs_iter: LIt,
+
+    /// A value giving us access to all right-hand side values
+    rhs: RHS<'a, R>,
+
+    /// A comparison predicate: Fn(&L, &R) -> core::cmp::Ordering
+    predicate: P,
+
+    /// One of: Inner, Outer, Semi, Anti
     join_type: JoinType,
 }
 
-impl<'a, LIt, R, P, L> Iterator for JoinedLeft<'a, LIt, R, P>
+grouped_terminal_methods!(JoinedGrouped);
+
+impl<'a, LIt, R, P> JoinedGrouped<'a, LIt, R, P> {
+    /// Searches RHS for `left`, returning the result to yield for it, if any.
+    fn join_left<L>(&self, left: L) -> Option<(L, Vec<&'a R>)>
+    where
+        P: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        if let JoinType::Anti = self.join_type {
+            if !self.rhs.has_value(&left, &self.predicate) {
+                return Some((left, Vec::new()));
+            }
+            return None;
+        }
+
+        let mut rs = Vec::new();
+        self.rhs.push_matches(&left, &self.predicate, &mut rs);
+
+        match self.join_type {
+            JoinType::Inner if rs.is_empty() => None,
+            JoinType::Inner | JoinType::Outer => Some((left, rs)),
+            JoinType::Semi | JoinType::Anti => unreachable!(),
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedGrouped<'a, LIt, R, P>
 where
     LIt: Iterator<Item = L>,
-    P: Fn(&L, &R) -> std::cmp::Ordering,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+            if let Some(result) = self.join_left(left) {
+                return Some(result);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.lhs_iter.size_hint();
+        match self.join_type {
+            // Exactly one result per LHS value
+            JoinType::Outer => (lower, upper),
+            _ => (0, upper),
+        }
+    }
+}
+
+/// Left records are taken from the back of LHS, but each record's matches are still in RHS order.
+impl<'a, LIt, R, P, L> DoubleEndedIterator for JoinedGrouped<'a, LIt, R, P>
+where
+    LIt: DoubleEndedIterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next_back()?;
+            if let Some(result) = self.join_left(left) {
+                return Some(result);
+            }
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> FusedIterator for JoinedGrouped<'a, LIt, R, P>
+where
+    LIt: FusedIterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+}
+
+/// The left records of a grouped join, as returned by [JoinedGrouped::left_keys], that will yield
+/// `L` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct LeftKeys<I, L> {
+    inner: I,
+
+    /// The last left record seen, held back until a different one follows it
+    pending: Option<L>,
+}
+
+impl<I, L, V> Iterator for LeftKeys<I, L>
+where
+    I: Iterator<Item = (L, V)>,
+    L: PartialEq,
 {
     type Item = L;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let left = self.lhs_iter.next()?;
+    fn next(&mut self) -> Option<Self::Item> {
+        for (left, _) in self.inner.by_ref() {
+            if self.pending.as_ref() == Some(&left) {
+                continue;
+            }
+
+            if let Some(previous) = self.pending.replace(left) {
+                return Some(previous);
+            }
+        }
+
+        self.pending.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let pending = usize::from(self.pending.is_some());
+        (
+            usize::from(pending + lower > 0),
+            upper.and_then(|u| u.checked_add(pending)),
+        )
+    }
+}
+
+/// The intermediate result of an [outer join](JoinableGrouped::outer_join_grouped) that will
+/// yield `(L, Vec<&R>)` values.
+///
+/// Because exactly one result is yielded per LHS value, this is an [ExactSizeIterator] whenever
+/// LHS is.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedOuter<'a, LIt, R, P> {
+    inner: JoinedGrouped<'a, LIt, R, P>,
+}
+
+grouped_terminal_methods!(JoinedGroupedOuter);
+
+impl<'a, LIt, R, P, L> Iterator for JoinedGroupedOuter<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, LIt, R, P, L> DoubleEndedIterator for JoinedGroupedOuter<'a, LIt, R, P>
+where
+    LIt: DoubleEndedIterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, LIt, R, P, L> ExactSizeIterator for JoinedGroupedOuter<'a, LIt, R, P>
+where
+    LIt: ExactSizeIterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+}
+
+impl<'a, LIt, R, P, L> FusedIterator for JoinedGroupedOuter<'a, LIt, R, P>
+where
+    LIt: FusedIterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+}
+
+/// The intermediate result of a
+/// [deduplicating grouped join](JoinableGrouped::outer_join_grouped_dedup) that will yield
+/// `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedDedup<'a, LIt, R, P, E> {
+    inner: JoinedGroupedOuter<'a, LIt, R, P>,
+
+    /// Whether two right-hand side values are duplicates: Fn(&R, &R) -> bool
+    eq: E,
+}
+
+impl<'a, LIt, R, P, E, L> Iterator for JoinedGroupedDedup<'a, LIt, R, P, E>
+where
+    LIt: Iterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    E: Fn(&R, &R) -> bool,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (left, mut rs) = self.inner.next()?;
+
+        // Move each distinct match down to follow those kept before it, preserving their order
+        let mut kept = 0;
+        for i in 0..rs.len() {
+            if !rs[..kept].iter().any(|k| (self.eq)(k, rs[i])) {
+                rs.swap(kept, i);
+                kept += 1;
+            }
+        }
+        rs.truncate(kept);
+
+        Some((left, rs))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, LIt, R, P, E, L> ExactSizeIterator for JoinedGroupedDedup<'a, LIt, R, P, E>
+where
+    LIt: ExactSizeIterator<Item = L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    E: Fn(&R, &R) -> bool,
+{
+}
+
+/// The intermediate result of a [match-counting join](JoinableGrouped::match_count_join) that will
+/// yield `(L, usize)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedMatchCount<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedMatchCount<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+        let count = self.rhs.count_matches(&left, &self.predicate);
+        Some((left, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lhs_iter.size_hint()
+    }
+}
+
+/// The intermediate result of a [mark join](JoinableGrouped::mark_join) that will yield
+/// `(L, bool)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedMark<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedMark<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+        let has_right = self.rhs.has_value(&left, &self.predicate);
+        Some((left, has_right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lhs_iter.size_hint()
+    }
+}
+
+/// The intermediate result of a [first-match semi-join](JoinableGrouped::semi_join_first) that
+/// will yield `(L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedSemiFirst<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedSemiFirst<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            if let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                return Some((left, r));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.lhs_iter.size_hint().1)
+    }
+}
+
+/// The intermediate result of a [coalesced join](JoinableGrouped::coalesced_inner_join) that will
+/// yield `(Vec<L>, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedCoalesced<'a, LIt, R, P, F, L> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Extracts the key that consecutive LHS values are grouped by: Fn(&L) -> K
+    key: F,
+
+    /// The first LHS value of the next group, which was pulled to end the previous one
+    next_left: Option<L>,
+}
+
+impl<'a, LIt, R, P, F, K, L> Iterator for JoinedCoalesced<'a, LIt, R, P, F, L>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    F: Fn(&L) -> K,
+    K: PartialEq,
+{
+    type Item = (Vec<L>, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let first = match self.next_left.take() {
+                Some(left) => left,
+                None => self.lhs_iter.next()?,
+            };
+
+            let mut rs = Vec::new();
+            self.rhs.push_matches(&first, &self.predicate, &mut rs);
+
+            let key = (self.key)(&first);
+            let mut lefts = vec![first];
+            for left in self.lhs_iter.by_ref() {
+                if (self.key)(&left) != key {
+                    self.next_left = Some(left);
+                    break;
+                }
+                lefts.push(left);
+            }
+
+            if !rs.is_empty() {
+                return Some((lefts, rs));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a semi- or anti-join that will yield `L` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedLeft<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+    join_type: JoinType,
+}
+
+impl<'a, LIt, R, P> JoinedLeft<'a, LIt, R, P>
+where
+    Self: Iterator,
+{
+    /// Clears `out` and fills it with every remaining result, reusing its existing allocation.
+    pub fn collect_into(self, out: &mut Vec<<Self as Iterator>::Item>) {
+        out.clear();
+        out.extend(self);
+    }
+
+    /// Pushes every remaining result into `collector`, which is returned. Pass `&mut collector`
+    /// to keep ownership of it.
+    pub fn collect_with<C>(self, collector: C) -> C
+    where
+        C: Collector<<Self as Iterator>::Item>,
+    {
+        crate::collector::collect_with(self, collector)
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedLeft<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let has_right = self.rhs.has_value(&left, &self.predicate);
+
+            match self.join_type {
+                JoinType::Semi if has_right => return Some(left),
+                JoinType::Anti if !has_right => return Some(left),
+
+                JoinType::Semi => {}
+                JoinType::Anti => {}
+
+                JoinType::Inner => unreachable!(),
+                JoinType::Outer => unreachable!(),
+            }
+        }
+    }
+}
+
+impl<'a, LIt, R, P, L> FusedIterator for JoinedLeft<'a, LIt, R, P>
+where
+    LIt: FusedIterator<Item = L>,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+}
+
+/// The intermediate result of a reducing join that will yield `(L, Option<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedReduce<'a, LIt, R, P, F> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Picks one of two matching right-hand side values: Fn(&R, &R) -> &R
+    reduce: F,
+}
+
+impl<'a, LIt, R, P, L, F> Iterator for JoinedReduce<'a, LIt, R, P, F>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    F: Fn(&'a R, &'a R) -> &'a R,
+{
+    type Item = (L, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+
+        let mut range = self.rhs.get_range(&left, &self.predicate);
+        let mut best = None;
+        while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+            best = Some(match best {
+                Some(b) => (self.reduce)(b, r),
+                None => r,
+            });
+        }
+
+        Some((left, best))
+    }
+}
+
+/// The intermediate result of a partitioning join that will yield `(L, Vec<&R>, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedPartitioned<'a, LIt, R, P, S> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Decides which partition a match goes in: Fn(&R) -> bool
+    split: S,
+}
+
+impl<'a, LIt, R, P, L, S> Iterator for JoinedPartitioned<'a, LIt, R, P, S>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    S: Fn(&R) -> bool,
+{
+    type Item = (L, Vec<&'a R>, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let (mut yes, mut no) = (Vec::new(), Vec::new());
+            while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                if (self.split)(r) {
+                    yes.push(r);
+                } else {
+                    no.push(r);
+                }
+            }
+
+            if !yes.is_empty() || !no.is_empty() {
+                return Some((left, yes, no));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a mapping join that will yield `(L, Vec<T>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedMap<'a, LIt, R, P, F> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Transforms each matching right-hand side value: Fn(&R) -> T
+    f: F,
+}
+
+impl<'a, LIt, R, P, L, F, T> Iterator for JoinedGroupedMap<'a, LIt, R, P, F>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    F: Fn(&R) -> T,
+{
+    type Item = (L, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut ts = Vec::new();
+            while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                ts.push((self.f)(r));
+            }
+
+            if !ts.is_empty() {
+                return Some((left, ts));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a deduplicating grouped join that will yield `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedUnique<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedGroupedUnique<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    R: PartialEq + 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut rs: Vec<&R> = Vec::new();
+            while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                if !rs.contains(&r) {
+                    rs.push(r);
+                }
+            }
+
+            if !rs.is_empty() {
+                return Some((left, rs));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a [boundary join](JoinableGrouped::inner_join_boundaries) that will
+/// yield `(L, usize)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedBoundaries<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedBoundaries<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.lhs_iter.next()?;
+        let count = self.rhs.count_matches(&left, &self.predicate);
+        Some((left, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lhs_iter.size_hint()
+    }
+}
+
+/// The intermediate result of a limited grouped join that will yield `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedLimited<'a, LIt, R, P> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// The most matches to collect for each left-hand side value
+    n: usize,
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedGroupedLimited<'a, LIt, R, P>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut rs = Vec::new();
+            while rs.len() < self.n {
+                match self.rhs.next_match(&left, &self.predicate, &mut range) {
+                    Some((_, r)) => rs.push(r),
+                    None => break,
+                }
+            }
+
+            if !rs.is_empty() {
+                return Some((left, rs));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a capped grouped join that will yield `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedCapped<'a, LIt, R, P, O> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// The most matches to collect for each left-hand side value
+    cap: usize,
+
+    /// Receives every match beyond the cap: FnMut(&L, &R)
+    overflow: O,
+}
+
+impl<'a, LIt, R, P, L, O> Iterator for JoinedGroupedCapped<'a, LIt, R, P, O>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    O: FnMut(&L, &R),
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut rs = Vec::new();
+            while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                if rs.len() < self.cap {
+                    rs.push(r);
+                } else {
+                    (self.overflow)(&left, r);
+                }
+            }
+
+            if !rs.is_empty() {
+                return Some((left, rs));
+            }
+        }
+    }
+}
+
+/// The intermediate result of an aggregating join that will yield `(L, Vec<&R>, A)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedAgg<'a, LIt, R, P, F> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Aggregates each group of matching right-hand side values: Fn(&[&R]) -> A
+    agg: F,
+}
+
+impl<'a, LIt, R, P, L, F, A> Iterator for JoinedGroupedAgg<'a, LIt, R, P, F>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    F: Fn(&[&'a R]) -> A,
+{
+    type Item = (L, Vec<&'a R>, A);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut rs = Vec::new();
+            self.rhs.push_matches(&left, &self.predicate, &mut rs);
+
+            if !rs.is_empty() {
+                let a = (self.agg)(&rs);
+                return Some((left, rs, a));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a weighted join that will yield `(L, Vec<(&R, f64)>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedWeighted<'a, LIt, R, P, W> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Weighs each match: Fn(&L, &R) -> f64
+    weight: W,
+}
+
+impl<'a, LIt, R, P, L, W> Iterator for JoinedWeighted<'a, LIt, R, P, W>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    W: Fn(&L, &R) -> f64,
+{
+    type Item = (L, Vec<(&'a R, f64)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut weighted = Vec::new();
+            while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                weighted.push((r, (self.weight)(&left, r)));
+            }
+
+            if !weighted.is_empty() {
+                // A stable sort, so ties keep their RHS order
+                weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+                return Some((left, weighted));
+            }
+        }
+    }
+}
+
+/// A map from categories to the values in each, as yielded by a categorizing join.
+pub trait Categories<C, V>: Default {
+    /// Adds `value` to the end of `category`.
+    fn push(&mut self, category: C, value: V);
+
+    /// Whether there are no categories.
+    fn is_empty(&self) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl<C: Hash + Eq, V> Categories<C, V> for HashMap<C, Vec<V>> {
+    fn push(&mut self, category: C, value: V) {
+        self.entry(category).or_default().push(value);
+    }
+
+    fn is_empty(&self) -> bool {
+        HashMap::is_empty(self)
+    }
+}
+
+impl<C: Ord, V> Categories<C, V> for BTreeMap<C, Vec<V>> {
+    fn push(&mut self, category: C, value: V) {
+        self.entry(category).or_default().push(value);
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeMap::is_empty(self)
+    }
+}
+
+/// The intermediate result of a categorizing join that will yield `(L, M)` values, where `M` maps
+/// categories to matches.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedCategorized<'a, LIt, R, P, F, M> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Categorizes each matching right-hand side value: Fn(&R) -> C
+    category: F,
+    categories: PhantomData<M>,
+}
+
+impl<'a, LIt, R, P, L, F, C, M> Iterator for JoinedCategorized<'a, LIt, R, P, F, M>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    F: Fn(&R) -> C,
+    M: Categories<C, &'a R>,
+{
+    type Item = (L, M);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut categories = M::default();
+            while let Some((_, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                categories.push((self.category)(r), r);
+            }
+
+            if !categories.is_empty() {
+                return Some((left, categories));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a checked grouped join that will yield `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedGroupedChecked<'a, LIt, R, P, C> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Compares members of a group in debug builds: Fn(&R, &R) -> Ordering
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    right_cmp: C,
+}
+
+impl<'a, LIt, R, P, L, C> Iterator for JoinedGroupedChecked<'a, LIt, R, P, C>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    C: Fn(&R, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut range = self.rhs.get_range(&left, &self.predicate);
+            let mut rs = Vec::new();
+            #[cfg(debug_assertions)]
+            let mut first = None;
+
+            while let Some((_i, r)) = self.rhs.next_match(&left, &self.predicate, &mut range) {
+                #[cfg(debug_assertions)]
+                match first {
+                    None => first = Some((_i, r)),
+                    Some((j, f)) => assert!(
+                        (self.right_cmp)(f, r).is_eq(),
+                        "RHS values at {j} and {_i} matched the same left value but are not equal"
+                    ),
+                }
+
+                rs.push(r);
+            }
+
+            if !rs.is_empty() {
+                return Some((left, rs));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a two-source join that will yield `(L, Vec<&RA>, Vec<&RB>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedTwoSources<'a, LIt, RA, RB, PA, PB> {
+    lhs_iter: LIt,
+    rhs_a: RHS<'a, RA>,
+    rhs_b: RHS<'a, RB>,
+    predicate_a: PA,
+    predicate_b: PB,
+}
+
+impl<'a, LIt, RA, RB, PA, PB, L> Iterator for JoinedTwoSources<'a, LIt, RA, RB, PA, PB>
+where
+    LIt: Iterator<Item = L>,
+    RA: 'a,
+    RB: 'a,
+    PA: Fn(&L, &RA) -> core::cmp::Ordering,
+    PB: Fn(&L, &RB) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a RA>, Vec<&'a RB>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let (mut a, mut b) = (Vec::new(), Vec::new());
+            self.rhs_a.push_matches(&left, &self.predicate_a, &mut a);
+            self.rhs_b.push_matches(&left, &self.predicate_b, &mut b);
+
+            if !a.is_empty() || !b.is_empty() {
+                return Some((left, a, b));
+            }
+        }
+    }
+}
+
+/// The intermediate result of a session join that will yield `(Vec<L>, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedSessions<'a, LIt, R, P, G, L> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// Whether two consecutive left values belong together: Fn(&L, &L) -> bool
+    same_session: G,
+
+    /// A left value (and its matches) that was pulled but belongs to the next session
+    pending: Option<(L, Vec<&'a R>)>,
+}
+
+impl<'a, LIt, R, P, G, L> Iterator for JoinedSessions<'a, LIt, R, P, G, L>
+where
+    LIt: Iterator<Item = L>,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+    G: Fn(&L, &L) -> bool,
+{
+    type Item = (Vec<L>, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, mut rights) = match self.pending.take() {
+            Some(p) => p,
+            None => {
+                let l = self.lhs_iter.next()?;
+                let mut rs = Vec::new();
+                self.rhs.push_matches(&l, &self.predicate, &mut rs);
+                (l, rs)
+            }
+        };
+
+        let mut lefts = vec![first];
+
+        for left in self.lhs_iter.by_ref() {
+            let mut rs = Vec::new();
+            self.rhs.push_matches(&left, &self.predicate, &mut rs);
+
+            let overlaps = rs
+                .iter()
+                .any(|r| rights.iter().any(|s| core::ptr::eq(*r, *s)));
+            let last = lefts.last().unwrap();
+
+            if overlaps || (self.same_session)(last, &left) {
+                for r in rs {
+                    if !rights.iter().any(|s| core::ptr::eq(r, *s)) {
+                        rights.push(r);
+                    }
+                }
+                lefts.push(left);
+            } else {
+                self.pending = Some((left, rs));
+                break;
+            }
+        }
+
+        Some((lefts, rights))
+    }
+}
+
+#[cfg(test)]
+const LEFT_ITEMS: [(usize, &str); 12] = [
+    (0, "zero"),
+    (0, "nil"),
+    (1, "one"),
+    (2, "two"),
+    (3, "three"),
+    (4, "four"),
+    (5, "five"),
+    (6, "six"),
+    (7, "seven"),
+    (8, "eight"),
+    (9, "nine"),
+    (10, "ten"),
+];
+
+#[cfg(test)]
+const RIGHT_ITEMS: [(usize, &str); 8] = [
+    (0, "zéro"),
+    (1, "un"),
+    (1, "uno"),
+    (1, "ichi"),
+    (2, "dos"),
+    (2, "deux"),
+    (3, "trois"),
+    (4, "quatre"),
+];
+
+#[test]
+fn test_left_semi() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .semi_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined.len(), 6);
+
+    assert_eq!(joined[0], &(0, "zero"));
+    assert_eq!(joined[1], &(0, "nil"));
+    assert_eq!(joined[2], &(1, "one"));
+    assert_eq!(joined[3], &(2, "two"));
+    assert_eq!(joined[4], &(3, "three"));
+    assert_eq!(joined[5], &(4, "four"));
+}
+
+#[test]
+fn test_grouped_into_iter() {
+    let expected = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .map(|(l, rs)| (*l, rs))
+        .collect::<Vec<_>>();
+
+    // Arrays and Vecs are consumed, yielding owned left records
+    let joined = LEFT_ITEMS
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, expected);
+
+    let left = LEFT_ITEMS.to_vec();
+    let joined = left
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, expected);
+
+    // A reference to a Vec yields references to its records
+    let left = LEFT_ITEMS.to_vec();
+    let joined = (&left)
+        .semi_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(joined.len(), 6);
+    assert_eq!(joined[0], &left[0]);
+}
+
+#[test]
+fn test_left_anti() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .anti_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined.len(), 6);
+
+    assert_eq!(joined[0], &(5, "five"));
+    assert_eq!(joined[1], &(6, "six"));
+    assert_eq!(joined[2], &(7, "seven"));
+    assert_eq!(joined[3], &(8, "eight"));
+    assert_eq!(joined[4], &(9, "nine"));
+    assert_eq!(joined[5], &(10, "ten"));
+}
+
+#[test]
+fn test_left_inner_grouped() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined.len(), 6);
+
+    let mut it = joined.into_iter();
+
+    assert_eq!(it.next(), Some((&(0, "zero"), vec![&(0, "zéro")])));
+    assert_eq!(it.next(), Some((&(0, "nil"), vec![&(0, "zéro")])));
+    assert_eq!(
+        it.next(),
+        Some((&(1, "one"), vec![&(1, "un"), &(1, "uno"), &(1, "ichi")]))
+    );
+    assert_eq!(
+        it.next(),
+        Some((&(2, "two"), vec![&(2, "dos"), &(2, "deux")]))
+    );
+    assert_eq!(it.next(), Some((&(3, "three"), vec![&(3, "trois")])));
+    assert_eq!(it.next(), Some((&(4, "four"), vec![&(4, "quatre")])));
+}
+
+#[test]
+fn test_left_outer_grouped() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .outer_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined.len(), 12);
+
+    let mut it = joined.into_iter();
+
+    assert_eq!(it.next(), Some((&(0, "zero"), vec![&(0, "zéro")])));
+    assert_eq!(it.next(), Some((&(0, "nil"), vec![&(0, "zéro")])));
+    assert_eq!(
+        it.next(),
+        Some((&(1, "one"), vec![&(1, "un"), &(1, "uno"), &(1, "ichi")]))
+    );
+    assert_eq!(
+        it.next(),
+        Some((&(2, "two"), vec![&(2, "dos"), &(2, "deux")]))
+    );
+    assert_eq!(it.next(), Some((&(3, "three"), vec![&(3, "trois")])));
+    assert_eq!(it.next(), Some((&(4, "four"), vec![&(4, "quatre")])));
+
+    // No matches here
+    assert_eq!(it.next(), Some((&(5, "five"), vec![])));
+    assert_eq!(it.next(), Some((&(6, "six"), vec![])));
+    assert_eq!(it.next(), Some((&(7, "seven"), vec![])));
+    assert_eq!(it.next(), Some((&(8, "eight"), vec![])));
+    assert_eq!(it.next(), Some((&(9, "nine"), vec![])));
+    assert_eq!(it.next(), Some((&(10, "ten"), vec![])));
+}
+
+#[test]
+fn test_left_outer_grouped_dedup() {
+    let left = [1, 2, 3];
+    let right = [(1, "un"), (2, "deux"), (1, "uno"), (1, "un"), (2, "deux")];
+    let mut sorted = right;
+    sorted.sort_by_key(|r| r.0);
+
+    for rhs in [RHS::new_unsorted(&right), RHS::new_sorted(&sorted)] {
+        let joined = left
+            .iter()
+            .outer_join_grouped_dedup(rhs, |l, r| (*l).cmp(&r.0), |a, b| a == b)
+            .map(|(l, rs)| (*l, rs.iter().map(|r| r.1).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            joined,
+            vec![(1, vec!["un", "uno"]), (2, vec!["deux"]), (3, vec![])]
+        );
+    }
+
+    // Only duplicates within a group are removed
+    let joined =
+        [1, 1]
+            .iter()
+            .outer_join_grouped_dedup(&right[..], |l, r| (*l).cmp(&r.0), |a, b| a == b);
+    assert_eq!(joined.len(), 2);
+    assert_eq!(joined.map(|(_, rs)| rs.len()).sum::<usize>(), 4);
+}
+
+#[test]
+fn test_inner_join_reduce() {
+    let customers = [(1, "ACME"), (2, "Contoso"), (3, "Foobar, Inc")];
+    let orders = [(1, 10.0), (1, 12.5), (1, 11.0), (2, 35.0)];
+
+    let reduced = customers
+        .iter()
+        .inner_join_reduce(
+            &orders[..],
+            |c, o| c.0.cmp(&o.0),
+            |a, b| {
+                if b.1 > a.1 {
+                    b
+                } else {
+                    a
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        reduced,
+        vec![
+            (&(1, "ACME"), Some(&(1, 12.5))),
+            (&(2, "Contoso"), Some(&(2, 35.0))),
+            (&(3, "Foobar, Inc"), None),
+        ]
+    );
+}
+
+#[test]
+fn test_inner_join_reduce_sorted() {
+    let rhs = RHS::new_sorted(&RIGHT_ITEMS);
+    let mut joined =
+        LEFT_ITEMS
+            .iter()
+            .inner_join_reduce(rhs, |l, r| l.0.cmp(&r.0), |a, b| a.min(b));
+
+    assert_eq!(joined.next(), Some((&(0, "zero"), Some(&(0, "zéro")))));
+    assert_eq!(joined.next(), Some((&(0, "nil"), Some(&(0, "zéro")))));
+    assert_eq!(joined.next(), Some((&(1, "one"), Some(&(1, "ichi")))));
+    assert_eq!(joined.next(), Some((&(2, "two"), Some(&(2, "deux")))));
+    assert_eq!(joined.nth(2), Some((&(5, "five"), None)));
+}
+
+#[test]
+fn test_inner_join_grouped_for_each() {
+    let mut buffer = Vec::with_capacity(8);
+    let mut groups = Vec::new();
+
+    LEFT_ITEMS.iter().inner_join_grouped_for_each(
+        &RIGHT_ITEMS[..],
+        |l, r| l.0.cmp(&r.0),
+        &mut buffer,
+        |l, rs| groups.push((l.1, rs.iter().map(|r| r.1).collect::<Vec<_>>())),
+    );
+
+    assert_eq!(
+        groups,
+        vec![
+            ("zero", vec!["zéro"]),
+            ("nil", vec!["zéro"]),
+            ("one", vec!["un", "uno", "ichi"]),
+            ("two", vec!["dos", "deux"]),
+            ("three", vec!["trois"]),
+            ("four", vec!["quatre"]),
+        ]
+    );
+
+    // The buffer was reused rather than replaced
+    assert_eq!(buffer.capacity(), 8);
+}
+
+#[test]
+fn test_inner_join_grouped_partition() {
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped_partition(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), |r| r.1.len() <= 3)
+        .skip(3);
+
+    assert_eq!(
+        joined.next(),
+        Some((&(2, "two"), vec![&(2, "dos")], vec![&(2, "deux")]))
+    );
+    assert_eq!(
+        joined.next(),
+        Some((&(3, "three"), vec![], vec![&(3, "trois")]))
+    );
+    assert_eq!(
+        joined.next(),
+        Some((&(4, "four"), vec![], vec![&(4, "quatre")]))
+    );
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_inner_join_grouped_map() {
+    let mut joined = LEFT_ITEMS.iter().skip(2).inner_join_grouped_map(
+        &RIGHT_ITEMS[..],
+        |l, r| l.0.cmp(&r.0),
+        |r| r.1.to_uppercase(),
+    );
+
+    assert_eq!(
+        joined.next(),
+        Some((&(1, "one"), vec!["UN".into(), "UNO".into(), "ICHI".into()]))
+    );
+    assert_eq!(
+        joined.next(),
+        Some((&(2, "two"), vec!["DOS".into(), "DEUX".into()]))
+    );
+    assert_eq!(joined.next(), Some((&(3, "three"), vec!["TROIS".into()])));
+    assert_eq!(joined.next(), Some((&(4, "four"), vec!["QUATRE".into()])));
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_inner_join_grouped_with_agg() {
+    // (customer id, amount)
+    let orders = [(1, 10), (2, 5), (1, 25), (3, 7), (1, 5)];
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped_with_agg(
+            &orders[..],
+            |l, r| l.0.cmp(&r.0),
+            |rs| (rs.len(), rs.iter().map(|r| r.1).sum::<i32>()),
+        )
+        .map(|(l, rs, agg)| (l.1, rs.len(), agg))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            ("one", 3, (3, 40)),
+            ("two", 1, (1, 5)),
+            ("three", 1, (1, 7))
+        ]
+    );
+}
+
+#[test]
+fn test_weighted_join() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .skip(2)
+        .take(2)
+        .weighted_join(
+            &RIGHT_ITEMS[..],
+            |l, r| l.0.cmp(&r.0),
+            |_, r| {
+                if r.1.starts_with('u') {
+                    1.0
+                } else {
+                    2.0
+                }
+            },
+        )
+        .map(|(l, rs)| (l.1, rs.into_iter().map(|(r, w)| (r.1, w)).collect()))
+        .collect::<Vec<(_, Vec<_>)>>();
+
+    // Equal weights keep their RHS order
+    assert_eq!(
+        joined,
+        vec![
+            ("one", vec![("ichi", 2.0), ("un", 1.0), ("uno", 1.0)]),
+            ("two", vec![("dos", 2.0), ("deux", 2.0)]),
+        ]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_inner_join_categorized() {
+    // (customer id, status, amount)
+    let orders = [
+        (1, "shipped", 10),
+        (2, "pending", 5),
+        (1, "pending", 25),
+        (1, "shipped", 5),
+    ];
+
+    let mut joined =
+        LEFT_ITEMS
+            .iter()
+            .inner_join_categorized(&orders[..], |l, r| l.0.cmp(&r.0), |r| r.1);
+
+    let (left, categories) = joined.next().unwrap();
+    assert_eq!(left, &(1, "one"));
+    assert_eq!(categories.len(), 2);
+    assert_eq!(categories["shipped"], vec![&orders[0], &orders[3]]);
+    assert_eq!(categories["pending"], vec![&orders[2]]);
+
+    let (left, categories) = joined.next().unwrap();
+    assert_eq!(left, &(2, "two"));
+    assert_eq!(categories["pending"], vec![&orders[1]]);
+    assert!(joined.next().is_none());
+
+    let ordered = LEFT_ITEMS
+        .iter()
+        .inner_join_categorized_ordered(&orders[..], |l, r| l.0.cmp(&r.0), |r| r.1)
+        .map(|(l, categories)| (l.1, categories.into_keys().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        ordered,
+        vec![
+            ("one", vec!["pending", "shipped"]),
+            ("two", vec!["pending"])
+        ]
+    );
+}
+
+#[test]
+fn test_grouped_join_checked() {
+    let joined = LEFT_ITEMS
+        .iter()
+        .grouped_join_checked(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), |a, b| a.0.cmp(&b.0))
+        .map(|(l, rs)| (l.1, rs.len()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined[2], ("one", 3));
+    assert_eq!(joined.len(), 6);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "RHS values at 0 and 1 matched the same left value but are not equal")]
+fn test_grouped_join_checked_violation() {
+    use core::cmp::Ordering;
+
+    // Matching within one isn't transitive: 1 is equal to both 0 and 2, but they aren't equal
+    let within_one = |l: &i32, r: &i32| match l.abs_diff(*r) {
+        0 | 1 => Ordering::Equal,
+        _ => l.cmp(r),
+    };
+
+    let left = [1];
+    let right = [0, 1, 5, 2];
+    left.into_iter()
+        .grouped_join_checked(&right[..], within_one, |a, b| a.cmp(b))
+        .for_each(drop);
+}
+
+#[test]
+fn test_inner_join_two_sources() {
+    // (customer id, item)
+    let orders = [(1, "apple"), (3, "cherry"), (1, "avocado")];
+
+    // (customer id, message)
+    let tickets = [(2, "late"), (3, "broken")];
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .inner_join_two_sources(
+            &orders[..],
+            RHS::new_sorted(&tickets),
+            |l, o| l.0.cmp(&o.0),
+            |l, t| l.0.cmp(&t.0),
+        )
+        .map(|(l, os, ts)| {
+            (
+                l.1,
+                os.iter().map(|o| o.1).collect::<Vec<_>>(),
+                ts.iter().map(|t| t.1).collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // The rest have no matches in either
+    assert_eq!(
+        joined,
+        vec![
+            ("one", vec!["apple", "avocado"], vec![]),
+            ("two", vec![], vec!["late"]),
+            ("three", vec!["cherry"], vec!["broken"]),
+        ]
+    );
+}
+
+#[test]
+fn test_collect_grouped_by_size() {
+    let descending = LEFT_ITEMS
+        .iter()
+        .collect_grouped_by_size(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), true)
+        .into_iter()
+        .map(|(l, rs)| (l.1, rs.len()))
+        .collect::<Vec<_>>();
 
-            let has_right = self.rhs.has_value(&left, &self.predicate);
+    assert_eq!(
+        descending,
+        vec![
+            ("one", 3),
+            ("two", 2),
+            ("zero", 1),
+            ("nil", 1),
+            ("three", 1),
+            ("four", 1)
+        ]
+    );
 
-            match self.join_type {
-                JoinType::Semi if has_right => return Some(left),
-                JoinType::Anti if !has_right => return Some(left),
+    let ascending = LEFT_ITEMS
+        .iter()
+        .collect_grouped_by_size(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), false)
+        .into_iter()
+        .map(|(l, rs)| (l.1, rs.len()))
+        .collect::<Vec<_>>();
 
-                JoinType::Semi => {}
-                JoinType::Anti => {}
+    assert_eq!(
+        ascending,
+        vec![
+            ("zero", 1),
+            ("nil", 1),
+            ("three", 1),
+            ("four", 1),
+            ("two", 2),
+            ("one", 3)
+        ]
+    );
+}
 
-                JoinType::Inner => unreachable!(),
-                JoinType::Outer => unreachable!(),
-            }
-        }
-    }
+#[test]
+fn test_split_matched() {
+    let (matched, unmatched) = LEFT_ITEMS
+        .iter()
+        .take(7)
+        .split_matched(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+
+    let matched = matched
+        .into_iter()
+        .map(|(l, rs)| (l.1, rs.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        matched,
+        vec![
+            ("zero", 1),
+            ("nil", 1),
+            ("one", 3),
+            ("two", 2),
+            ("three", 1),
+            ("four", 1)
+        ]
+    );
+    assert_eq!(unmatched, vec![&(5, "five")]);
 }
 
-#[cfg(test)]
-const LEFT_ITEMS: [(usize, &'static str); 12] = [
-    (0, "zero"),
-    (0, "nil"),
-    (1, "one"),
-    (2, "two"),
-    (3, "three"),
-    (4, "four"),
-    (5, "five"),
-    (6, "six"),
-    (7, "seven"),
-    (8, "eight"),
-    (9, "nine"),
-    (10, "ten"),
-];
+#[test]
+fn test_sample_matched() {
+    let pulled = core::cell::Cell::new(0);
+    let sample = LEFT_ITEMS
+        .iter()
+        .rev()
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .sample_matched(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0), 2)
+        .into_iter()
+        .map(|(l, rs)| (l.1, rs.len()))
+        .collect::<Vec<_>>();
 
-#[cfg(test)]
-const RIGHT_ITEMS: [(usize, &'static str); 8] = [
-    (0, "zéro"),
-    (1, "un"),
-    (1, "uno"),
-    (1, "ichi"),
-    (2, "dos"),
-    (2, "deux"),
-    (3, "trois"),
-    (4, "quatre"),
-];
+    // "ten" through "five" have no matches and so don't count towards the sample
+    assert_eq!(sample, vec![("four", 1), ("three", 1)]);
+    assert_eq!(pulled.get(), 8);
+}
 
 #[test]
-fn test_left_semi() {
-    let joined = LEFT_ITEMS
+fn test_session_join() {
+    // (minute, page)
+    let clicks = [
+        (0, 'a'),
+        (1, 'a'),
+        (2, 'b'),
+        (30, 'c'),
+        (40, 'c'),
+        (90, 'z'),
+    ];
+
+    // (page, title)
+    let pages = [('a', "home"), ('b', "cart"), ('c', "checkout")];
+
+    let sessions = clicks
         .iter()
-        .semi_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .session_join(
+            &pages[..],
+            |l, r| l.1.cmp(&r.0),
+            |prev, next| next.0 - prev.0 <= 5,
+        )
+        .map(|(ls, rs)| {
+            (
+                ls.iter().map(|l| l.0).collect::<Vec<_>>(),
+                rs.iter().map(|r| r.1).collect::<Vec<_>>(),
+            )
+        })
         .collect::<Vec<_>>();
 
-    assert_eq!(joined.len(), 6);
+    assert_eq!(
+        sessions,
+        vec![
+            // Adjacent clicks within the gap
+            (vec![0, 1, 2], vec!["home", "cart"]),
+            // A gapped click that still overlaps on page 'c'
+            (vec![30, 40], vec!["checkout"]),
+            // A gapped click with no overlap starts its own session, even without matches
+            (vec![90], vec![]),
+        ]
+    );
+}
 
-    assert_eq!(joined[0], &(0, "zero"));
-    assert_eq!(joined[1], &(0, "nil"));
-    assert_eq!(joined[2], &(1, "one"));
-    assert_eq!(joined[3], &(2, "two"));
-    assert_eq!(joined[4], &(3, "three"));
-    assert_eq!(joined[5], &(4, "four"));
+#[test]
+fn test_inner_join_with_right_mask() {
+    let (matched, mask) = LEFT_ITEMS
+        .iter()
+        .inner_join_with_right_mask(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_eq!(matched.len(), 6);
+    assert_eq!(mask, vec![true; RIGHT_ITEMS.len()]);
+
+    let (matched, mask) = [1, 3]
+        .into_iter()
+        .inner_join_with_right_mask(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.cmp(&r.0));
+    assert_eq!(matched.iter().map(|m| m.0).collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(
+        mask,
+        vec![false, true, true, true, false, false, true, false]
+    );
+
+    let orphans = RIGHT_ITEMS
+        .iter()
+        .zip(&mask)
+        .filter(|(_, m)| !**m)
+        .map(|(r, _)| r.1)
+        .collect::<Vec<_>>();
+    assert_eq!(orphans, vec!["zéro", "dos", "deux", "quatre"]);
+
+    // The mask follows the wrapped slice, not its sorted order
+    let right = (0..40).rev().collect::<Vec<_>>();
+    let partial = crate::PartialSort::new(&right, |a, b| a.cmp(b));
+    let (matched, mask) =
+        [39].inner_join_with_right_mask(RHS::new_partially_sorted(&partial), |l, r| l.cmp(r));
+    assert_eq!(matched, vec![(39, vec![&39])]);
+    assert_eq!(mask.iter().position(|m| *m), Some(0));
+    assert_eq!(mask.iter().filter(|m| **m).count(), 1);
 }
 
 #[test]
-fn test_left_anti() {
+fn test_inner_join_grouped_capped() {
+    let mut overflow = Vec::new();
     let joined = LEFT_ITEMS
         .iter()
-        .anti_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .inner_join_grouped_capped(
+            &RIGHT_ITEMS[..],
+            |l, r| l.0.cmp(&r.0),
+            2,
+            |l, r| overflow.push((l.1, r.1)),
+        )
+        .map(|(l, rs)| (l.1, rs.into_iter().map(|r| r.1).collect::<Vec<_>>()))
         .collect::<Vec<_>>();
 
-    assert_eq!(joined.len(), 6);
-
-    assert_eq!(joined[0], &(5, "five"));
-    assert_eq!(joined[1], &(6, "six"));
-    assert_eq!(joined[2], &(7, "seven"));
-    assert_eq!(joined[3], &(8, "eight"));
-    assert_eq!(joined[4], &(9, "nine"));
-    assert_eq!(joined[5], &(10, "ten"));
+    assert_eq!(
+        joined,
+        vec![
+            ("zero", vec!["zéro"]),
+            ("nil", vec!["zéro"]),
+            ("one", vec!["un", "uno"]),
+            ("two", vec!["dos", "deux"]),
+            ("three", vec!["trois"]),
+            ("four", vec!["quatre"]),
+        ]
+    );
+    assert_eq!(overflow, vec![("one", "ichi")]);
 }
 
 #[test]
-fn test_left_inner_grouped() {
-    let joined = LEFT_ITEMS
+fn test_collect_grouped_csr() {
+    let (lefts, offsets, rights) = LEFT_ITEMS
         .iter()
-        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .skip(1)
+        .collect_grouped_csr(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+
+    let lefts = lefts.iter().map(|l| l.1).collect::<Vec<_>>();
+    assert_eq!(lefts, vec!["nil", "one", "two", "three", "four"]);
+    assert_eq!(offsets, vec![0, 1, 4, 6, 7, 8]);
+    assert_eq!(rights.len(), RIGHT_ITEMS.len());
+
+    let groups = offsets
+        .windows(2)
+        .map(|w| rights[w[0]..w[1]].iter().map(|r| r.1).collect::<Vec<_>>())
         .collect::<Vec<_>>();
+    assert_eq!(groups[1], vec!["un", "uno", "ichi"]);
+    assert_eq!(groups[2], vec!["dos", "deux"]);
 
-    assert_eq!(joined.len(), 6);
+    let (lefts, offsets, rights) = LEFT_ITEMS
+        .iter()
+        .skip(6)
+        .collect_grouped_csr(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert!(lefts.is_empty() && rights.is_empty());
+    assert_eq!(offsets, vec![0]);
+}
 
-    let mut it = joined.into_iter();
+#[cfg(feature = "std")]
+#[test]
+fn test_pivot_join() {
+    // (store, region)
+    let stores = [("s1", "north"), ("s2", "south"), ("s3", "north")];
+    // (store, product category, amount)
+    let sales = [
+        ("s1", "fruit", 3),
+        ("s1", "veg", 5),
+        ("s1", "fruit", 4),
+        ("s2", "fruit", 1),
+        ("s3", "veg", 2),
+    ];
 
-    assert_eq!(it.next(), Some((&(0, "zero"), vec![&(0, "zéro")])));
-    assert_eq!(it.next(), Some((&(0, "nil"), vec![&(0, "zéro")])));
+    let pivot = stores.iter().pivot_join(
+        &sales[..],
+        |l, r| l.0.cmp(r.0),
+        |l| l.1,
+        |r| r.1,
+        |rs| rs.iter().map(|r| r.2).sum::<i32>(),
+    );
+
+    assert_eq!(pivot.len(), 3);
+    assert_eq!(pivot[&("north", "fruit")], 7);
+    assert_eq!(pivot[&("north", "veg")], 7);
+    assert_eq!(pivot[&("south", "fruit")], 1);
+    assert!(!pivot.contains_key(&("south", "veg")));
+}
+
+#[test]
+fn test_longest_matched_run() {
+    let right = [1, 2, 3, 5, 6, 7, 8, 10];
+    let predicate = |l: &i32, r: &i32| l.cmp(r);
+
+    // Matched runs of 3 and 4, interrupted by unmatched rows
+    let left = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let run = left
+        .into_iter()
+        .longest_matched_run(RHS::new_sorted(&right), predicate);
+    assert_eq!(run, 4..8);
+
+    // Equally long runs give the first
+    let left = [1, 2, 4, 5, 6, 9];
     assert_eq!(
-        it.next(),
-        Some((&(1, "one"), vec![&(1, "un"), &(1, "uno"), &(1, "ichi")]))
+        left.into_iter().longest_matched_run(&right[..], predicate),
+        0..2
     );
+
+    let left = [0, 4, 9];
+    let run = left.into_iter().longest_matched_run(&right[..], predicate);
+    assert!(run.is_empty());
+}
+
+#[test]
+fn test_inner_join_grouped_unique() {
+    let right = [
+        (1, "un"),
+        (1, "uno"),
+        (1, "un"),
+        (2, "deux"),
+        (2, "deux"),
+        (3, "trois"),
+    ];
+
+    for rhs in [RHS::new_unsorted(&right), RHS::new_sorted(&right)] {
+        let joined = [1, 2, 4]
+            .into_iter()
+            .inner_join_grouped_unique(rhs, |l, r| l.cmp(&r.0))
+            .map(|(l, rs)| (l, rs.into_iter().map(|r| r.1).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(joined, vec![(1, vec!["un", "uno"]), (2, vec!["deux"])]);
+    }
+}
+
+#[test]
+fn test_inner_join_boundaries() {
+    let boundaries = LEFT_ITEMS
+        .iter()
+        .skip(1)
+        .take(4)
+        .inner_join_boundaries(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0));
+    let mut matches = crate::Joinable::inner_join(
+        LEFT_ITEMS.iter().skip(1).take(4),
+        RHS::new_sorted(&RIGHT_ITEMS),
+        |l, r| l.0.cmp(&r.0),
+    )
+    .map(|(_, r)| r);
+
+    let mut groups = Vec::new();
+    for (l, n) in boundaries {
+        let rs = matches.by_ref().take(n).map(|r| r.1).collect::<Vec<_>>();
+        assert_eq!(rs.len(), n);
+        groups.push((l.1, rs));
+    }
+
     assert_eq!(
-        it.next(),
-        Some((&(2, "two"), vec![&(2, "dos"), &(2, "deux")]))
+        groups,
+        vec![
+            ("nil", vec!["zéro"]),
+            ("one", vec!["un", "uno", "ichi"]),
+            ("two", vec!["dos", "deux"]),
+            ("three", vec!["trois"]),
+        ]
     );
-    assert_eq!(it.next(), Some((&(3, "three"), vec![&(3, "trois")])));
-    assert_eq!(it.next(), Some((&(4, "four"), vec![&(4, "quatre")])));
+    assert_eq!(matches.next(), None);
+
+    // Unmatched lefts have a count of zero
+    let counts = LEFT_ITEMS
+        .iter()
+        .skip(4)
+        .inner_join_boundaries(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .map(|(_, n)| n)
+        .collect::<Vec<_>>();
+    assert_eq!(counts, vec![1, 1, 0, 0, 0, 0, 0, 0]);
 }
 
 #[test]
-fn test_left_outer_grouped() {
+fn test_match_histogram() {
+    let expected = [(0, 6), (1, 4), (2, 1), (3, 1)].into_iter().collect();
+
+    let histogram = LEFT_ITEMS
+        .iter()
+        .match_histogram(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_eq!(histogram, expected);
+
+    let histogram = LEFT_ITEMS
+        .iter()
+        .match_histogram(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0));
+    assert_eq!(histogram, expected);
+}
+
+#[test]
+fn test_match_count_join() {
+    for rhs in [
+        RHS::new_unsorted(&RIGHT_ITEMS),
+        RHS::new_sorted(&RIGHT_ITEMS),
+    ] {
+        let counts = LEFT_ITEMS
+            .iter()
+            .take(6)
+            .match_count_join(rhs, |l, r| l.0.cmp(&r.0))
+            .map(|(l, n)| (l.1, n))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            counts,
+            vec![
+                ("zero", 1),
+                ("nil", 1),
+                ("one", 3),
+                ("two", 2),
+                ("three", 1),
+                ("four", 1)
+            ]
+        );
+    }
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .match_count_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_eq!(joined.filter(|(_, n)| *n == 0).count(), 6);
+}
+
+#[test]
+fn test_mark_join() {
+    for rhs in [
+        RHS::new_unsorted(&RIGHT_ITEMS),
+        RHS::new_sorted(&RIGHT_ITEMS),
+    ] {
+        let marked = LEFT_ITEMS
+            .iter()
+            .mark_join(rhs, |l, r| l.0.cmp(&r.0))
+            .collect::<Vec<_>>();
+        assert_eq!(marked.len(), LEFT_ITEMS.len());
+
+        let semi = LEFT_ITEMS
+            .iter()
+            .semi_join(rhs, |l, r| l.0.cmp(&r.0))
+            .collect::<Vec<_>>();
+        let matched = marked
+            .iter()
+            .filter(|(_, m)| *m)
+            .map(|(l, _)| *l)
+            .collect::<Vec<_>>();
+        assert_eq!(matched, semi);
+
+        let anti = LEFT_ITEMS
+            .iter()
+            .anti_join(rhs, |l, r| l.0.cmp(&r.0))
+            .collect::<Vec<_>>();
+        let unmatched = marked
+            .iter()
+            .filter(|(_, m)| !*m)
+            .map(|(l, _)| *l)
+            .collect::<Vec<_>>();
+        assert_eq!(unmatched, anti);
+    }
+}
+
+#[test]
+fn test_grouped_size_hint() {
     let joined = LEFT_ITEMS
+        .iter()
+        .outer_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_eq!(
+        joined.size_hint(),
+        (LEFT_ITEMS.len(), Some(LEFT_ITEMS.len()))
+    );
+    assert_eq!(joined.len(), LEFT_ITEMS.len());
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_eq!(joined.size_hint(), (0, Some(LEFT_ITEMS.len())));
+    joined.next();
+    assert_eq!(joined.size_hint(), (0, Some(LEFT_ITEMS.len() - 1)));
+}
+
+#[test]
+fn test_anti_join_grouped() {
+    let anti = LEFT_ITEMS
+        .iter()
+        .anti_join_grouped(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    let outer = LEFT_ITEMS
         .iter()
         .outer_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .filter(|(_, rs)| rs.is_empty())
         .collect::<Vec<_>>();
 
-    assert_eq!(joined.len(), 12);
+    assert_eq!(anti.len(), 6);
+    assert_eq!(anti, outer);
+    assert_eq!(anti[0].0, &(5, "five"));
+}
 
-    let mut it = joined.into_iter();
+#[test]
+fn test_inner_join_grouped_limited() {
+    let comparisons = core::cell::Cell::new(0);
+    let predicate = |l: &&(usize, &str), r: &(usize, &str)| {
+        comparisons.set(comparisons.get() + 1);
+        l.0.cmp(&r.0)
+    };
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .skip(2)
+        .take(2)
+        .inner_join_grouped_limited(&RIGHT_ITEMS[..], predicate, 2)
+        .map(|(l, rs)| (l.1, rs.into_iter().map(|r| r.1).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
 
-    assert_eq!(it.next(), Some((&(0, "zero"), vec![&(0, "zéro")])));
-    assert_eq!(it.next(), Some((&(0, "nil"), vec![&(0, "zéro")])));
     assert_eq!(
-        it.next(),
-        Some((&(1, "one"), vec![&(1, "un"), &(1, "uno"), &(1, "ichi")]))
+        joined,
+        vec![("one", vec!["un", "uno"]), ("two", vec!["dos", "deux"])]
+    );
+
+    // The scan for "one" stops at "uno", and for "two" at "deux"
+    assert_eq!(comparisons.get(), 3 + 6);
+
+    let joined = LEFT_ITEMS.iter().inner_join_grouped_limited(
+        RHS::new_sorted(&RIGHT_ITEMS),
+        |l, r| l.0.cmp(&r.0),
+        0,
+    );
+    assert_eq!(joined.count(), 0);
+}
+
+#[test]
+fn test_fused() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_fused(&joined);
+    assert_eq!(joined.by_ref().count(), 6);
+    assert_eq!(joined.next(), None);
+    assert_eq!(joined.next(), None);
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .semi_join(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0));
+    assert_fused(&joined);
+    assert_eq!(joined.by_ref().count(), 6);
+    assert_eq!(joined.next(), None);
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_grouped_rev() {
+    for rhs in [
+        RHS::new_unsorted(&RIGHT_ITEMS),
+        RHS::new_sorted(&RIGHT_ITEMS),
+    ] {
+        let mut forward = LEFT_ITEMS
+            .iter()
+            .inner_join_grouped(rhs, |l, r| l.0.cmp(&r.0))
+            .collect::<Vec<_>>();
+        let backward = LEFT_ITEMS
+            .iter()
+            .inner_join_grouped(rhs, |l, r| l.0.cmp(&r.0))
+            .rev()
+            .collect::<Vec<_>>();
+
+        // Only the order of left records is reversed
+        forward.reverse();
+        assert_eq!(backward, forward);
+    }
+
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .outer_join_grouped(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0));
+    assert_eq!(joined.next_back(), Some((&(10, "ten"), vec![])));
+    assert_eq!(joined.next(), Some((&(0, "zero"), vec![&(0, "zéro")])));
+
+    // Unmatched left records at the back are skipped
+    let mut joined = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+    assert_eq!(
+        joined.next_back(),
+        Some((&(4, "four"), vec![&(4, "quatre")]))
     );
     assert_eq!(
-        it.next(),
-        Some((&(2, "two"), vec![&(2, "dos"), &(2, "deux")]))
+        joined.next_back(),
+        Some((&(3, "three"), vec![&(3, "trois")]))
     );
-    assert_eq!(it.next(), Some((&(3, "three"), vec![&(3, "trois")])));
-    assert_eq!(it.next(), Some((&(4, "four"), vec![&(4, "quatre")])));
+}
 
-    // No matches here
-    assert_eq!(it.next(), Some((&(5, "five"), vec![])));
-    assert_eq!(it.next(), Some((&(6, "six"), vec![])));
-    assert_eq!(it.next(), Some((&(7, "seven"), vec![])));
-    assert_eq!(it.next(), Some((&(8, "eight"), vec![])));
-    assert_eq!(it.next(), Some((&(9, "nine"), vec![])));
-    assert_eq!(it.next(), Some((&(10, "ten"), vec![])));
+#[test]
+fn test_semi_join_first() {
+    for rhs in [
+        RHS::new_unsorted(&RIGHT_ITEMS),
+        RHS::new_sorted(&RIGHT_ITEMS),
+    ] {
+        let joined = LEFT_ITEMS
+            .iter()
+            .semi_join_first(rhs, |l, r| l.0.cmp(&r.0))
+            .map(|(l, r)| (l.1, r.1))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            joined,
+            vec![
+                ("zero", "zéro"),
+                ("nil", "zéro"),
+                ("one", "un"),
+                ("two", "dos"),
+                ("three", "trois"),
+                ("four", "quatre")
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_coalesced_inner_join() {
+    let comparisons = core::cell::Cell::new(0);
+    let predicate = |l: &&(usize, &str), r: &(usize, &str)| {
+        comparisons.set(comparisons.get() + 1);
+        l.0.cmp(&r.0)
+    };
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .coalesced_inner_join(&RIGHT_ITEMS[..], predicate, |l| l.0)
+        .map(|(ls, rs)| {
+            let ls = ls.iter().map(|l| l.1).collect::<Vec<_>>();
+            let rs = rs.iter().map(|r| r.1).collect::<Vec<_>>();
+            (ls, rs)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![
+            (vec!["zero", "nil"], vec!["zéro"]),
+            (vec!["one"], vec!["un", "uno", "ichi"]),
+            (vec!["two"], vec!["dos", "deux"]),
+            (vec!["three"], vec!["trois"]),
+            (vec!["four"], vec!["quatre"]),
+        ]
+    );
+
+    // "zero" and "nil" share one scan of RHS
+    assert_eq!(comparisons.get(), 11 * RIGHT_ITEMS.len());
+
+    let joined = LEFT_ITEMS
+        .iter()
+        .coalesced_inner_join(RHS::new_sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0), |l| l.0)
+        .map(|(ls, rs)| (ls.len(), rs.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec![(2, 1), (1, 3), (1, 2), (1, 1), (1, 1)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_into_hashmap() {
+    let map = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .into_hashmap(|l| l.0);
+
+    assert_eq!(map.len(), 5);
+
+    // "zero" and "nil" share a key, so both of their matches are kept
+    assert_eq!(map[&0], vec![&(0, "zéro"), &(0, "zéro")]);
+    assert_eq!(map[&1].len(), 3);
+    assert!(!map.contains_key(&5));
+}
+
+#[test]
+fn test_left_keys() {
+    let semi = LEFT_ITEMS
+        .iter()
+        .semi_join(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .collect::<Vec<_>>();
+
+    let left_keys = LEFT_ITEMS
+        .iter()
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0))
+        .left_keys()
+        .collect::<Vec<_>>();
+
+    assert_eq!(left_keys.len(), 6);
+    assert_eq!(left_keys, semi);
+
+    // Unmatched records are dropped before runs of equal records are collapsed
+    let left_keys = [1, 1, 2, 7, 2, 2, 3]
+        .inner_join_grouped(&RIGHT_ITEMS[..], |l, r| l.cmp(&r.0))
+        .left_keys()
+        .collect::<Vec<_>>();
+    assert_eq!(left_keys, vec![1, 2, 3]);
+
+    let left_keys = [1, 1, 7, 7, 1]
+        .outer_join_grouped(&RIGHT_ITEMS[..], |l, r| l.cmp(&r.0))
+        .left_keys();
+    assert_eq!(left_keys.size_hint(), (1, Some(5)));
+    assert_eq!(left_keys.collect::<Vec<_>>(), vec![1, 7, 1]);
 }
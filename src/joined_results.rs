@@ -0,0 +1,139 @@
+use crate::joined::JoinedEachInner;
+use crate::{Joinable, RHS};
+
+/// A trait allowing the joining of a fallible left-hand side (LHS) and a right-hand side ([RHS])
+/// dataset, such as rows coming out of a parser that yields `Result<&L, E>`.
+pub trait JoinableResults<'a, LIt, R, P, L, E> {
+    /// Joins LHS and RHS, keeping only records from left that have one or more matches in right.
+    ///
+    /// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+    ///
+    /// Like [Joinable::inner_join], this function yields one `Ok((&L, &R))` for every match. The
+    /// first `Err` pulled from LHS is yielded as-is, after which iteration stops.
+    fn inner_join_results_lhs(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedResultsInner<'a, LIt, R, P, L, E>;
+}
+
+impl<'a, LIt, R, P, L, E> JoinableResults<'a, LIt, R, P, L, E> for LIt
+where
+    LIt: Iterator<Item = Result<&'a L, E>>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    fn inner_join_results_lhs(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedResultsInner<'a, LIt, R, P, L, E> {
+        let lhs = OkLefts {
+            lhs_iter: self,
+            err: None,
+            done: false,
+        };
+
+        JoinedResultsInner {
+            inner: lhs.inner_join(rhs, predicate),
+        }
+    }
+}
+
+/// Adapts an iterator of `Result<&L, E>` into an iterator of `&L`, stashing the first error and
+/// ending iteration when one is found.
+struct OkLefts<LIt, E> {
+    lhs_iter: LIt,
+    err: Option<E>,
+    done: bool,
+}
+
+impl<'a, LIt, L, E> Iterator for OkLefts<LIt, E>
+where
+    LIt: Iterator<Item = Result<&'a L, E>>,
+    L: 'a,
+{
+    type Item = &'a L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.lhs_iter.next() {
+            Some(Ok(l)) => Some(l),
+            Some(Err(e)) => {
+                self.err = Some(e);
+                self.done = true;
+                None
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+/// The intermediate result of an inner join over a fallible LHS that will yield
+/// `Result<(&L, &R), E>` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedResultsInner<'a, LIt, R, P, L, E> {
+    inner: JoinedEachInner<'a, OkLefts<LIt, E>, R, P, L>,
+}
+
+impl<'a, LIt, R, P, L, E> Iterator for JoinedResultsInner<'a, LIt, R, P, L, E>
+where
+    LIt: Iterator<Item = Result<&'a L, E>>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = Result<(&'a L, &'a R), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next() {
+            Some(pair) => Some(Ok(pair)),
+            // LHS stopped, either because it ran out or because it hit an error
+            None => self.inner.lhs_iter.err.take().map(Err),
+        }
+    }
+}
+
+#[cfg(test)]
+const RIGHT_ITEMS: [(usize, &str); 4] = [(1, "un"), (1, "uno"), (2, "dos"), (3, "trois")];
+
+#[test]
+fn test_inner_results_lhs() {
+    let left = [(1, "one"), (2, "two"), (3, "three")];
+    let mut joined = left
+        .iter()
+        .map(Ok::<_, String>)
+        .inner_join_results_lhs(&RIGHT_ITEMS[..], |l, r| l.0.cmp(&r.0));
+
+    assert_eq!(joined.next(), Some(Ok((&(1, "one"), &(1, "un")))));
+    assert_eq!(joined.next(), Some(Ok((&(1, "one"), &(1, "uno")))));
+    assert_eq!(joined.next(), Some(Ok((&(2, "two"), &(2, "dos")))));
+    assert_eq!(joined.next(), Some(Ok((&(3, "three"), &(3, "trois")))));
+    assert_eq!(joined.next(), None);
+}
+
+#[test]
+fn test_inner_results_lhs_err() {
+    let one = (1, "one");
+    let three = (3, "three");
+    let left: Vec<Result<&(usize, &str), &str>> = vec![Ok(&one), Err("bad row"), Ok(&three)];
+
+    let mut joined = left
+        .into_iter()
+        .inner_join_results_lhs(RHS::Sorted(&RIGHT_ITEMS), |l, r| l.0.cmp(&r.0));
+
+    assert_eq!(joined.next(), Some(Ok((&(1, "one"), &(1, "un")))));
+    assert_eq!(joined.next(), Some(Ok((&(1, "one"), &(1, "uno")))));
+    assert_eq!(joined.next(), Some(Err("bad row")));
+
+    // Rows after the error are never joined
+    assert_eq!(joined.next(), None);
+    assert_eq!(joined.next(), None);
+}
@@ -0,0 +1,119 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::RHS;
+
+/// Merges several left-hand streams, each sorted by `left_key`, and joins the merged records
+/// against RHS, keeping only records that have one or more matches.
+///
+/// The specified predicate returns a [core::cmp::Ordering] comparing left and right records.
+/// Results are yielded as `(L, Vec<&R>)` in global key order, as though the streams had been
+/// concatenated and sorted; records with equal keys are taken from earlier streams first. The
+/// streams are merged lazily using a binary heap over the head of each.
+pub fn kway_merge_join<'a, I, L, K, F, R, P>(
+    left_streams: Vec<I>,
+    left_key: F,
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+) -> KWayMergeJoin<'a, I, L, K, F, R, P>
+where
+    I: Iterator<Item = L>,
+    F: Fn(&L) -> K,
+    K: Ord,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    let mut streams = left_streams;
+    let mut heads = Vec::with_capacity(streams.len());
+    let mut heap = BinaryHeap::with_capacity(streams.len());
+
+    for (i, stream) in streams.iter_mut().enumerate() {
+        let head = stream.next();
+        if let Some(l) = &head {
+            heap.push(Reverse((left_key(l), i)));
+        }
+        heads.push(head);
+    }
+
+    KWayMergeJoin {
+        streams,
+        heads,
+        heap,
+        left_key,
+        rhs: rhs.into(),
+        predicate,
+    }
+}
+
+/// The intermediate result of [kway_merge_join] that will yield `(L, Vec<&R>)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct KWayMergeJoin<'a, I, L, K, F, R, P> {
+    streams: Vec<I>,
+
+    /// The next value from each stream, which is in `heap` if present
+    heads: Vec<Option<L>>,
+
+    /// The key of each stream's head along with the stream's index, smallest first
+    heap: BinaryHeap<Reverse<(K, usize)>>,
+
+    left_key: F,
+    rhs: RHS<'a, R>,
+    predicate: P,
+}
+
+impl<'a, I, L, K, F, R, P> Iterator for KWayMergeJoin<'a, I, L, K, F, R, P>
+where
+    I: Iterator<Item = L>,
+    F: Fn(&L) -> K,
+    K: Ord,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (L, Vec<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((_, i)) = self.heap.pop()?;
+
+            // Replace this stream's head with its next value
+            let next = self.streams[i].next();
+            if let Some(l) = &next {
+                self.heap.push(Reverse(((self.left_key)(l), i)));
+            }
+            let left = core::mem::replace(&mut self.heads[i], next)?;
+
+            let mut rs = Vec::new();
+            self.rhs.push_matches(&left, &self.predicate, &mut rs);
+
+            if !rs.is_empty() {
+                return Some((left, rs));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_kway_merge_join() {
+    let shard_a = vec![(1, "a1"), (4, "a4"), (5, "a5")];
+    let shard_b = vec![(2, "b2"), (4, "b4"), (9, "b9")];
+    let right = [
+        (1, "un"),
+        (2, "deux"),
+        (4, "quatre"),
+        (4, "vier"),
+        (5, "cinq"),
+    ];
+
+    let joined = kway_merge_join(
+        vec![shard_a.into_iter(), shard_b.into_iter()],
+        |l| l.0,
+        RHS::new_sorted(&right),
+        |l, r| l.0.cmp(&r.0),
+    )
+    .map(|(l, rs)| (l.1, rs.len()))
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![("a1", 1), ("b2", 1), ("a4", 2), ("b4", 2), ("a5", 1)]
+    );
+}
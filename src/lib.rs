@@ -70,11 +70,83 @@
 //!     .anti_join(&orders[..], |c, o| c.id.cmp(&o.customer_id))
 //!     .collect();
 //! ```
+//!
+//! Without the default `std` feature, the crate is `no_std` and depends only on `alloc`. Items
+//! that need hashing, such as `HashedRHS`, are then unavailable.
+// Tests always link std, so that they can use its prelude whichever features are enabled
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
 mod joined_grouped;
 pub use joined_grouped::JoinableGrouped;
 
 mod joined;
-pub use joined::Joinable;
+pub use joined::{Joinable, Match, MatchHandle};
+
+mod nested_loop;
+pub use nested_loop::NestedLoopJoinable;
+
+mod joined_by_key;
+pub use joined_by_key::{JoinStrategy, JoinableByKey};
+
+mod collector;
+pub use collector::Collector;
+
+mod joined_results;
+pub use joined_results::JoinableResults;
 
 mod rhs;
-pub use rhs::RHS;
+#[cfg(feature = "std")]
+pub use rhs::HashedRHS;
+pub use rhs::{explain_join, point_join, OwnedRHS, PartialSort, SortError, RHS};
+
+#[cfg(feature = "std")]
+mod analysis;
+#[cfg(feature = "std")]
+pub use analysis::{build_inverted_index, detect_left_duplicates, DuplicateKeys};
+
+mod columnar;
+pub use columnar::indexed_key_join;
+
+mod cardinality;
+pub use cardinality::{Cardinality, CardinalityError};
+
+#[cfg(feature = "std")]
+mod map_left;
+#[cfg(feature = "std")]
+pub use map_left::inner_join_map_left;
+
+mod metrics;
+pub use metrics::JoinMetrics;
+
+mod resumable;
+pub use resumable::{resumable_inner_join, ResumableInnerJoin};
+
+mod kway;
+pub use kway::kway_merge_join;
+
+mod aggregate;
+pub use aggregate::inner_join_vs_aggregate;
+
+mod membership;
+pub use membership::{anti_join_by, semi_join_by};
+
+mod fallible;
+pub use fallible::try_inner_join;
+
+#[cfg(feature = "file-join")]
+mod file_join;
+#[cfg(feature = "file-join")]
+pub use file_join::file_join;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::par_inner_join_grouped;
+
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub use serialize::GroupedRow;
@@ -0,0 +1,91 @@
+use std::collections::hash_map;
+use std::collections::HashMap;
+
+use crate::RHS;
+
+/// Joins the values of a map against RHS, keeping only entries that have one or more matches in
+/// right.
+///
+/// The specified predicate returns a [core::cmp::Ordering] comparing left values and right
+/// records. Each match is yielded as `(&K, &L, &R)`, so the map key is carried through the join
+/// rather than needing to be recovered afterwards.
+///
+/// Entries are visited in the map's iteration order, which is unspecified.
+pub fn inner_join_map_left<'a, K, L, R, P, S>(
+    left_map: &'a HashMap<K, L, S>,
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+) -> JoinedMapLeft<'a, K, L, R, P>
+where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    JoinedMapLeft {
+        lhs_iter: left_map.iter(),
+        current_left: None,
+        rhs: rhs.into(),
+        rhs_range: (1, 0),
+        predicate,
+    }
+}
+
+/// The intermediate result of [inner_join_map_left] that will yield `(&K, &L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedMapLeft<'a, K, L, R, P> {
+    lhs_iter: hash_map::Iter<'a, K, L>,
+
+    /// The current map entry
+    current_left: Option<(&'a K, &'a L)>,
+
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+}
+
+impl<'a, K, L, R, P> Iterator for JoinedMapLeft<'a, K, L, R, P>
+where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a K, &'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((k, l)) = self.current_left {
+                if let Some((_, r)) = self.rhs.next_match(l, &self.predicate, &mut self.rhs_range) {
+                    return Some((k, l, r));
+                }
+
+                // No matches remain for this entry
+                self.current_left = None;
+            }
+
+            let (k, l) = self.lhs_iter.next()?;
+            self.rhs_range = self.rhs.get_range(l, &self.predicate);
+            self.current_left = Some((k, l));
+        }
+    }
+}
+
+#[test]
+fn test_inner_join_map_left() {
+    let mut customers = HashMap::new();
+    customers.insert("ann", 1);
+    customers.insert("bob", 2);
+    customers.insert("cy", 3);
+
+    // (customer id, item)
+    let orders = [(1, "apple"), (3, "cherry"), (1, "avocado")];
+
+    let mut joined = inner_join_map_left(&customers, &orders[..], |l, r| l.cmp(&r.0))
+        .map(|(k, l, r)| (*k, *l, r.1))
+        .collect::<Vec<_>>();
+    joined.sort();
+
+    assert_eq!(
+        joined,
+        vec![
+            ("ann", 1, "apple"),
+            ("ann", 1, "avocado"),
+            ("cy", 3, "cherry")
+        ]
+    );
+}
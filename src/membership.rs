@@ -0,0 +1,85 @@
+/// Keeps only the records from left for which `membership` returns `true`.
+///
+/// This is a [semi_join](crate::JoinableGrouped::semi_join) where there is no RHS to search;
+/// instead, `membership` answers directly whether a matching right record exists. It may be backed
+/// by anything, such as a set, a bloom filter, or an external service.
+pub fn semi_join_by<LIt, F>(left: LIt, membership: F) -> JoinedMembership<LIt::IntoIter, F>
+where
+    LIt: IntoIterator,
+    F: Fn(&LIt::Item) -> bool,
+{
+    JoinedMembership {
+        lhs_iter: left.into_iter(),
+        membership,
+        keep_members: true,
+    }
+}
+
+/// Keeps only the records from left for which `membership` returns `false`.
+///
+/// This is an [anti_join](crate::JoinableGrouped::anti_join) where there is no RHS to search;
+/// see [semi_join_by].
+pub fn anti_join_by<LIt, F>(left: LIt, membership: F) -> JoinedMembership<LIt::IntoIter, F>
+where
+    LIt: IntoIterator,
+    F: Fn(&LIt::Item) -> bool,
+{
+    JoinedMembership {
+        lhs_iter: left.into_iter(),
+        membership,
+        keep_members: false,
+    }
+}
+
+/// The intermediate result of [semi_join_by] or [anti_join_by] that will yield `L` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedMembership<LIt, F> {
+    lhs_iter: LIt,
+
+    /// Whether a matching right record exists: Fn(&L) -> bool
+    membership: F,
+
+    /// `true` for a semi-join, `false` for an anti-join
+    keep_members: bool,
+}
+
+impl<LIt, L, F> Iterator for JoinedMembership<LIt, F>
+where
+    LIt: Iterator<Item = L>,
+    F: Fn(&L) -> bool,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            if (self.membership)(&left) == self.keep_members {
+                return Some(left);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_semi_anti_join_by() {
+    use std::collections::HashSet;
+
+    let customers = [(1, "ann"), (2, "bob"), (3, "cy"), (4, "di")];
+    let with_orders = [1, 3].into_iter().collect::<HashSet<_>>();
+    let has_orders = |c: &&(i32, &str)| with_orders.contains(&c.0);
+
+    let semi = semi_join_by(&customers, has_orders)
+        .map(|c| c.1)
+        .collect::<Vec<_>>();
+    assert_eq!(semi, vec!["ann", "cy"]);
+
+    let anti = anti_join_by(&customers, has_orders)
+        .map(|c| c.1)
+        .collect::<Vec<_>>();
+    assert_eq!(anti, vec!["bob", "di"]);
+
+    // Owned values work too, such as with a closure over an external check
+    let even = semi_join_by(1..=6, |n| n % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(even, vec![2, 4, 6]);
+}
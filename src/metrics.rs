@@ -0,0 +1,143 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use crate::RHS;
+
+/// Running counts for an instrumented join.
+#[derive(Default)]
+struct Counts {
+    predicate_calls: Cell<usize>,
+    binary_searches: Cell<usize>,
+    linear_comparisons: Cell<usize>,
+}
+
+impl Counts {
+    fn increment(cell: &Cell<usize>) {
+        cell.set(cell.get() + 1);
+    }
+}
+
+/// A handle to the metrics of an [instrumented join](crate::Joinable::instrumented_inner_join).
+///
+/// The handle is populated as the join is iterated, so it only reflects the work done so far.
+pub struct JoinMetrics {
+    counts: Rc<Counts>,
+}
+
+impl JoinMetrics {
+    /// The total number of times the join predicate has been called.
+    pub fn predicate_calls(&self) -> usize {
+        self.counts.predicate_calls.get()
+    }
+
+    /// The number of binary searches of a sorted RHS.
+    pub fn binary_searches(&self) -> usize {
+        self.counts.binary_searches.get()
+    }
+
+    /// The number of predicate calls made while linearly scanning an unsorted RHS.
+    pub fn linear_comparisons(&self) -> usize {
+        self.counts.linear_comparisons.get()
+    }
+}
+
+/// The intermediate result of an instrumented inner join that will yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedInstrumented<'a, LIt, R, P, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+    rhs_range: (usize, usize),
+    predicate: P,
+    counts: Rc<Counts>,
+}
+
+impl<'a, LIt, R, P, L> JoinedInstrumented<'a, LIt, R, P, L> {
+    pub(crate) fn new(lhs_iter: LIt, rhs: RHS<'a, R>, predicate: P) -> (Self, JoinMetrics) {
+        let counts = Rc::new(Counts::default());
+
+        let metrics = JoinMetrics {
+            counts: counts.clone(),
+        };
+        (
+            Self {
+                lhs_iter,
+                current_left: None,
+                rhs,
+                rhs_range: (1, 0),
+                predicate,
+                counts,
+            },
+            metrics,
+        )
+    }
+}
+
+impl<'a, LIt, R, P, L> Iterator for JoinedInstrumented<'a, LIt, R, P, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let counts = &self.counts;
+        let predicate = &self.predicate;
+        let searching = |l: &L, r: &R| {
+            Counts::increment(&counts.predicate_calls);
+            predicate(l, r)
+        };
+        let scanning = |l: &L, r: &R| {
+            Counts::increment(&counts.predicate_calls);
+            Counts::increment(&counts.linear_comparisons);
+            predicate(l, r)
+        };
+
+        loop {
+            if let Some(left) = self.current_left {
+                if let Some((_, r)) = self.rhs.next_match(left, &scanning, &mut self.rhs_range) {
+                    return Some((left, r));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.lhs_iter.next()?;
+
+            // Searched without get_range, whose debug checks would count as predicate calls
+            self.rhs_range = if self.rhs.is_sorted() {
+                Counts::increment(&counts.binary_searches);
+                self.rhs.get_bounds(left, &searching)
+            } else {
+                (0, self.rhs.len())
+            };
+            self.current_left = Some(left);
+        }
+    }
+}
+
+#[test]
+fn test_instrumented_inner_join() {
+    use crate::Joinable;
+    let left = [1, 2, 3, 4];
+    let right = (0..100).collect::<Vec<_>>();
+
+    let (joined, metrics) = left
+        .iter()
+        .instrumented_inner_join(&right[..], |l, r| l.cmp(r));
+    assert_eq!(joined.count(), 4);
+    assert_eq!(metrics.binary_searches(), 0);
+    assert_eq!(metrics.linear_comparisons(), 400);
+    assert_eq!(metrics.predicate_calls(), 400);
+
+    let (joined, metrics) = left
+        .iter()
+        .instrumented_inner_join(RHS::new_sorted(&right), |l, r| l.cmp(r));
+    assert_eq!(joined.count(), 4);
+    assert_eq!(metrics.binary_searches(), 4);
+    assert_eq!(metrics.linear_comparisons(), 0);
+    assert_eq!(metrics.predicate_calls(), 64);
+}
@@ -0,0 +1,365 @@
+use crate::RHS;
+
+/// A trait for joins that compare every record from left with every record from right, rather
+/// than searching RHS with a `Fn(&L, &R) -> Ordering` predicate.
+///
+/// These complement [Joinable](crate::Joinable), whose methods all take such a predicate. As with
+/// [Joinable](crate::Joinable), LHS values are borrowed. Because every pair of records is
+/// considered, whether RHS is [sorted](RHS::Sorted) makes no difference.
+pub trait NestedLoopJoinable<'a, LIt, L> {
+    /// Pairs every record from left with every record from right: the Cartesian product of LHS
+    /// and RHS.
+    ///
+    /// Results are yielded in row-major order, with each left record paired with every right
+    /// record, in RHS order, before moving on to the next left record.
+    fn cross_join<R>(self, rhs: impl Into<RHS<'a, R>>) -> JoinedCross<'a, LIt, R, L>
+    where
+        R: 'a;
+
+    /// Joins LHS and RHS, yielding every pair of records for which `predicate` returns `true`.
+    ///
+    /// Unlike an `Ordering` predicate, which can only express equality on a sortable key, this
+    /// allows non-equi joins such as inequalities, bands, and interval overlaps. Every left record
+    /// is compared with every right record, so the join is O(n * m). Results are yielded in the
+    /// same order as [cross_join](NestedLoopJoinable::cross_join).
+    fn theta_join<R, P>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedTheta<'a, LIt, R, L, P>
+    where
+        R: 'a,
+        P: Fn(&L, &R) -> bool;
+
+    /// Joins LHS and RHS, yielding every pair of records whose `similarity` is at or above
+    /// `threshold`, such as names that are spelled slightly differently.
+    ///
+    /// `similarity` scores a left and right record, with higher scores being more similar; unlike
+    /// the `Ordering` predicates of [Joinable](crate::Joinable), it can't be used to search RHS.
+    /// Every pair of records is scored, so the join is O(n * m). Results are yielded in the same
+    /// order as [cross_join](NestedLoopJoinable::cross_join).
+    fn fuzzy_join<R, S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        similarity: S,
+        threshold: f64,
+    ) -> JoinedFuzzy<'a, LIt, R, L, S>
+    where
+        R: 'a,
+        S: Fn(&L, &R) -> f64;
+
+    /// Joins LHS and RHS as [fuzzy_join](NestedLoopJoinable::fuzzy_join) does, but yields only the
+    /// most similar right record for each left record.
+    ///
+    /// Left records with no right record scoring at or above `threshold` are dropped. Of equally
+    /// similar right records, the first in RHS is yielded.
+    fn best_fuzzy_join<R, S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        similarity: S,
+        threshold: f64,
+    ) -> JoinedBestFuzzy<'a, LIt, R, S>
+    where
+        R: 'a,
+        S: Fn(&L, &R) -> f64;
+}
+
+impl<'a, LIt, L> NestedLoopJoinable<'a, LIt, L> for LIt
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+{
+    fn cross_join<R>(self, rhs: impl Into<RHS<'a, R>>) -> JoinedCross<'a, LIt, R, L>
+    where
+        R: 'a,
+    {
+        JoinedCross {
+            lhs_iter: self,
+            current_left: None,
+            rhs: rhs.into(),
+            next_right: 0,
+        }
+    }
+
+    fn theta_join<R, P>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+    ) -> JoinedTheta<'a, LIt, R, L, P>
+    where
+        R: 'a,
+        P: Fn(&L, &R) -> bool,
+    {
+        JoinedTheta {
+            pairs: self.cross_join(rhs),
+            predicate,
+        }
+    }
+
+    fn fuzzy_join<R, S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        similarity: S,
+        threshold: f64,
+    ) -> JoinedFuzzy<'a, LIt, R, L, S>
+    where
+        R: 'a,
+        S: Fn(&L, &R) -> f64,
+    {
+        JoinedFuzzy {
+            pairs: self.cross_join(rhs),
+            similarity,
+            threshold,
+        }
+    }
+
+    fn best_fuzzy_join<R, S>(
+        self,
+        rhs: impl Into<RHS<'a, R>>,
+        similarity: S,
+        threshold: f64,
+    ) -> JoinedBestFuzzy<'a, LIt, R, S>
+    where
+        R: 'a,
+        S: Fn(&L, &R) -> f64,
+    {
+        JoinedBestFuzzy {
+            lhs_iter: self,
+            rhs: rhs.into(),
+            similarity,
+            threshold,
+        }
+    }
+}
+
+/// The intermediate result of a [cross join](NestedLoopJoinable::cross_join) that will yield
+/// `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedCross<'a, LIt, R, L> {
+    lhs_iter: LIt,
+    current_left: Option<&'a L>,
+    rhs: RHS<'a, R>,
+
+    /// The index of the next RHS value to pair with the current LHS value
+    next_right: usize,
+}
+
+impl<'a, LIt, R, L> Iterator for JoinedCross<'a, LIt, R, L>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(left) = self.current_left {
+                if let Some(r) = self.rhs.get(self.next_right) {
+                    self.next_right += 1;
+                    return Some((left, r));
+                }
+
+                // Every RHS value has been paired with this LHS value
+                self.current_left = None;
+            }
+
+            self.current_left = Some(self.lhs_iter.next()?);
+            self.next_right = 0;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rhs.len();
+        let current = match self.current_left {
+            Some(_) => len - self.next_right.min(len),
+            None => 0,
+        };
+
+        let (lower, upper) = self.lhs_iter.size_hint();
+        let lower = lower.saturating_mul(len).saturating_add(current);
+        let upper = upper
+            .and_then(|upper| upper.checked_mul(len))
+            .and_then(|upper| upper.checked_add(current));
+        (lower, upper)
+    }
+}
+
+/// The intermediate result of a [theta join](NestedLoopJoinable::theta_join) that will yield
+/// `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedTheta<'a, LIt, R, L, P> {
+    /// Every pair of LHS and RHS values, to be filtered by the predicate
+    pairs: JoinedCross<'a, LIt, R, L>,
+    predicate: P,
+}
+
+impl<'a, LIt, R, L, P> Iterator for JoinedTheta<'a, LIt, R, L, P>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    P: Fn(&L, &R) -> bool,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &self.predicate;
+        self.pairs.find(|(l, r)| predicate(l, r))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.pairs.size_hint().1)
+    }
+}
+
+/// The intermediate result of a [fuzzy join](NestedLoopJoinable::fuzzy_join) that will yield
+/// `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedFuzzy<'a, LIt, R, L, S> {
+    /// Every pair of LHS and RHS values, to be filtered by their similarity
+    pairs: JoinedCross<'a, LIt, R, L>,
+
+    /// Scores how similar a left and right value are: Fn(&L, &R) -> f64
+    similarity: S,
+    threshold: f64,
+}
+
+impl<'a, LIt, R, L, S> Iterator for JoinedFuzzy<'a, LIt, R, L, S>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    S: Fn(&L, &R) -> f64,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (similarity, threshold) = (&self.similarity, self.threshold);
+        self.pairs.find(|(l, r)| similarity(l, r) >= threshold)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.pairs.size_hint().1)
+    }
+}
+
+/// The intermediate result of a [best fuzzy join](NestedLoopJoinable::best_fuzzy_join) that will
+/// yield `(&L, &R)` values.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct JoinedBestFuzzy<'a, LIt, R, S> {
+    lhs_iter: LIt,
+    rhs: RHS<'a, R>,
+
+    /// Scores how similar a left and right value are: Fn(&L, &R) -> f64
+    similarity: S,
+    threshold: f64,
+}
+
+impl<'a, LIt, R, S, L> Iterator for JoinedBestFuzzy<'a, LIt, R, S>
+where
+    LIt: Iterator<Item = &'a L>,
+    L: 'a,
+    R: 'a,
+    S: Fn(&L, &R) -> f64,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let left = self.lhs_iter.next()?;
+
+            let mut best: Option<(f64, &'a R)> = None;
+            let mut i = 0;
+            while let Some(r) = self.rhs.get(i) {
+                let score = (self.similarity)(left, r);
+                if score >= self.threshold && best.is_none_or(|(b, _)| score > b) {
+                    best = Some((score, r));
+                }
+                i += 1;
+            }
+
+            if let Some((_, r)) = best {
+                return Some((left, r));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cross_join() {
+    let sizes = ["S", "M", "L"];
+    let colors = ["red", "blue"];
+
+    let mut joined = sizes.iter().cross_join(&colors[..]);
+    assert_eq!(joined.size_hint(), (6, Some(6)));
+
+    joined.next();
+    assert_eq!(joined.size_hint(), (5, Some(5)));
+
+    let joined = joined.map(|(s, c)| format!("{s}-{c}")).collect::<Vec<_>>();
+    assert_eq!(joined, vec!["S-blue", "M-red", "M-blue", "L-red", "L-blue"]);
+
+    // Sortedness is ignored, and an empty side gives no pairs
+    let joined = sizes.iter().cross_join(RHS::new_sorted(&colors));
+    assert_eq!(joined.count(), 6);
+    assert_eq!(sizes.iter().cross_join(&colors[..0]).count(), 0);
+
+    // An LHS of inexact length gives inexact bounds
+    let joined = sizes.iter().filter(|_| true).cross_join(&colors[..]);
+    assert_eq!(joined.size_hint(), (0, Some(6)));
+}
+
+#[test]
+fn test_theta_join() {
+    // (timestamp, event)
+    let events = [(5, "boot"), (12, "login"), (31, "logout")];
+
+    // (start, end, shift)
+    let shifts = [(0, 10, "night"), (10, 20, "day"), (8, 15, "overlap")];
+
+    let joined = events
+        .iter()
+        .theta_join(&shifts[..], |l, r| r.0 <= l.0 && l.0 < r.1)
+        .map(|(l, r)| (l.1, r.2))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![("boot", "night"), ("login", "day"), ("login", "overlap")]
+    );
+}
+
+#[test]
+fn test_fuzzy_join() {
+    // The fraction of positions at which two names have the same letter
+    fn similarity(l: &&str, r: &&str) -> f64 {
+        let same = l.chars().zip(r.chars()).filter(|(a, b)| a == b).count();
+        same as f64 / l.len().max(r.len()) as f64
+    }
+
+    let left = ["jon", "mary", "zed"];
+    let right = ["john", "joan", "marie", "mary"];
+
+    let joined = left
+        .iter()
+        .fuzzy_join(&right[..], similarity, 0.5)
+        .map(|(l, r)| (*l, *r))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        joined,
+        vec![
+            ("jon", "john"),
+            ("jon", "joan"),
+            ("mary", "marie"),
+            ("mary", "mary")
+        ]
+    );
+
+    let joined = left
+        .iter()
+        .best_fuzzy_join(&right[..], similarity, 0.5)
+        .map(|(l, r)| (*l, *r))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec![("jon", "john"), ("mary", "mary")]);
+}
@@ -0,0 +1,95 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::RHS;
+
+/// Joins the records from left against RHS in parallel, as
+/// [inner_join_grouped](crate::JoinableGrouped::inner_join_grouped) does, keeping only records
+/// that have one or more matches.
+///
+/// The specified predicate returns a [std::cmp::Ordering] comparing left and right records. LHS is
+/// split across rayon's thread pool, with every thread searching the same RHS. Results are
+/// collected in LHS order, so they are the same as the sequential join's.
+///
+/// A [partially sorted](RHS::PartiallySorted) RHS can't be sorted from several threads at once,
+/// so it is searched as though it were unsorted, in O(n), with matches in RHS order.
+pub fn par_inner_join_grouped<'a, I, L, R, P>(
+    left: I,
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+) -> Vec<(L, Vec<&'a R>)>
+where
+    I: IntoParallelIterator<Item = L>,
+    L: Send,
+    R: Sync,
+    P: Fn(&L, &R) -> std::cmp::Ordering + Sync,
+{
+    let shared = SharedRHS::from(rhs.into());
+
+    left.into_par_iter()
+        .filter_map(|left| {
+            let mut rs = Vec::new();
+            shared.to_rhs().push_matches(&left, &predicate, &mut rs);
+
+            if rs.is_empty() {
+                None
+            } else {
+                Some((left, rs))
+            }
+        })
+        .collect()
+}
+
+/// The parts of an [RHS] that can be shared between threads, from which each thread rebuilds its
+/// own [RHS] to search.
+enum SharedRHS<'a, R> {
+    Unsorted(&'a [R]),
+    Sorted(&'a [R]),
+    Chunks(&'a [&'a [R]]),
+    SortedChunks(&'a [&'a [R]]),
+}
+
+impl<'a, R> From<RHS<'a, R>> for SharedRHS<'a, R> {
+    fn from(rhs: RHS<'a, R>) -> Self {
+        match rhs {
+            RHS::Unsorted(values) => SharedRHS::Unsorted(values),
+            RHS::Sorted(values) => SharedRHS::Sorted(values),
+            RHS::Chunks(chunks) => SharedRHS::Chunks(chunks),
+            RHS::SortedChunks(chunks) => SharedRHS::SortedChunks(chunks),
+            RHS::PartiallySorted(partial) => SharedRHS::Unsorted(partial.values()),
+        }
+    }
+}
+
+impl<'a, R> SharedRHS<'a, R> {
+    fn to_rhs(&self) -> RHS<'a, R> {
+        match *self {
+            SharedRHS::Unsorted(values) => RHS::Unsorted(values),
+            SharedRHS::Sorted(values) => RHS::Sorted(values),
+            SharedRHS::Chunks(chunks) => RHS::Chunks(chunks),
+            SharedRHS::SortedChunks(chunks) => RHS::SortedChunks(chunks),
+        }
+    }
+}
+
+#[test]
+fn test_par_inner_join_grouped() {
+    use crate::JoinableGrouped;
+    let left = (0..1000).collect::<Vec<_>>();
+    let right = (0..500).map(|i| (i * 3 % 1000, i)).collect::<Vec<_>>();
+    let predicate = |l: &i32, r: &(i32, i32)| l.cmp(&r.0);
+
+    let sequential = left
+        .iter()
+        .copied()
+        .inner_join_grouped(&right[..], predicate)
+        .collect::<Vec<_>>();
+
+    let parallel = par_inner_join_grouped(left.clone(), &right[..], predicate);
+    assert_eq!(parallel, sequential);
+
+    let mut sorted = right.clone();
+    sorted.sort();
+    let parallel = par_inner_join_grouped(left, RHS::new_sorted(&sorted), predicate);
+    assert_eq!(parallel.len(), sequential.len());
+    assert!(parallel.iter().all(|(l, rs)| rs.iter().all(|r| r.0 == *l)));
+}
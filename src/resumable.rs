@@ -0,0 +1,143 @@
+use crate::RHS;
+
+/// Joins a slice of left records and RHS as [inner_join](crate::Joinable::inner_join) does,
+/// tracking how far through LHS the join has gotten so that it can be resumed later.
+///
+/// See [ResumableInnerJoin] for checkpointing and resuming.
+pub fn resumable_inner_join<'a, L, R, P>(
+    left: &'a [L],
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+) -> ResumableInnerJoin<'a, L, R, P>
+where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    ResumableInnerJoin::resume_at(left, rhs, predicate, 0)
+}
+
+/// An inner join over a slice of left records that will yield `(&L, &R)` values and can be
+/// checkpointed and resumed.
+///
+/// [position](ResumableInnerJoin::position) gives the index in LHS to resume from, which can be
+/// saved and later passed to [resume_at](ResumableInnerJoin::resume_at) with the same LHS and an
+/// equivalent RHS. The resumed join yields the results that the original had yet to, except that
+/// if it stopped partway through the matches of a left record, that record's matches are all
+/// yielded again.
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct ResumableInnerJoin<'a, L, R, P> {
+    left: &'a [L],
+    rhs: RHS<'a, R>,
+    predicate: P,
+
+    /// The index of the next LHS value to search for
+    next_left: usize,
+
+    /// The index of the LHS value currently being searched for
+    current_left: Option<usize>,
+    rhs_range: (usize, usize),
+
+    /// The next result, found ahead of time so that the position is known to be after every
+    /// yielded result. Holds the index of its LHS value.
+    pending: Option<(usize, &'a L, &'a R)>,
+}
+
+impl<'a, L, R, P> ResumableInnerJoin<'a, L, R, P>
+where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    /// Resumes a join from `position`, as previously returned by
+    /// [position](ResumableInnerJoin::position).
+    pub fn resume_at(
+        left: &'a [L],
+        rhs: impl Into<RHS<'a, R>>,
+        predicate: P,
+        position: usize,
+    ) -> Self {
+        Self {
+            left,
+            rhs: rhs.into(),
+            predicate,
+            next_left: position.min(left.len()),
+            current_left: None,
+            rhs_range: (1, 0),
+            pending: None,
+        }
+    }
+
+    /// The index in LHS from which the join can be resumed. Every result for the records before
+    /// it has already been yielded, and none for the records after it have.
+    pub fn position(&self) -> usize {
+        match self.pending {
+            Some((i, _, _)) => i,
+            None => self.next_left,
+        }
+    }
+
+    /// Finds the next result along with the index of its LHS value.
+    fn find_next(&mut self) -> Option<(usize, &'a L, &'a R)> {
+        loop {
+            if let Some(i) = self.current_left {
+                let left = &self.left[i];
+                if let Some((_, r)) =
+                    self.rhs
+                        .next_match(left, &self.predicate, &mut self.rhs_range)
+                {
+                    return Some((i, left, r));
+                }
+
+                // No matches remain for this LHS value
+                self.current_left = None;
+            }
+
+            let left = self.left.get(self.next_left)?;
+            self.rhs_range = self.rhs.get_range(left, &self.predicate);
+            self.current_left = Some(self.next_left);
+            self.next_left += 1;
+        }
+    }
+}
+
+impl<'a, L, R, P> Iterator for ResumableInnerJoin<'a, L, R, P>
+where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, l, r) = match self.pending.take() {
+            Some(pending) => pending,
+            None => self.find_next()?,
+        };
+
+        self.pending = self.find_next();
+        Some((l, r))
+    }
+}
+
+#[test]
+fn test_resumable_inner_join() {
+    let left = [1, 2, 2, 3, 4, 5];
+    let right = [(1, "un"), (2, "deux"), (2, "dos"), (4, "quatre")];
+    let predicate = |l: &i32, r: &(i32, &str)| l.cmp(&r.0);
+
+    let all = resumable_inner_join(&left, &right[..], predicate).collect::<Vec<_>>();
+    assert_eq!(all.len(), 6);
+
+    let mut joined = resumable_inner_join(&left, &right[..], predicate);
+    assert_eq!(joined.position(), 0);
+    let mut output = joined.by_ref().take(3).collect::<Vec<_>>();
+
+    // Both matches of the first "2" were yielded, so the second "2" is next
+    let position = joined.position();
+    assert_eq!(position, 2);
+
+    let resumed =
+        ResumableInnerJoin::resume_at(&left, RHS::new_sorted(&right), predicate, position);
+    output.extend(resumed);
+    assert_eq!(output, all);
+
+    // Stopping partway through a record's matches resumes from that record
+    let mut joined = resumable_inner_join(&left, &right[..], predicate);
+    joined.by_ref().take(2).for_each(drop);
+    assert_eq!(joined.position(), 1);
+}
@@ -1,4 +1,83 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Finds every record in `rhs` matching a single left value, in RHS order.
+///
+/// The specified predicate returns a [core::cmp::Ordering] comparing the left value and right
+/// records. This is the degenerate join of a single left record, for simple lookups; a
+/// [sorted](RHS::Sorted) RHS is binary searched.
+pub fn point_join<'a, L, R, P>(left: &L, rhs: impl Into<RHS<'a, R>>, predicate: P) -> Vec<&'a R>
+where
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    let mut matches = Vec::new();
+    rhs.into().push_matches(left, &predicate, &mut matches);
+    matches
+}
+
+/// Compares a single left value against the records of `rhs`, returning each compared record's
+/// index along with the predicate's result. This is a diagnostic for finding out why a join
+/// produced fewer matches than expected.
+///
+/// For unsorted RHS, every record is compared. For sorted RHS, only the records where the binary
+/// search lands are, along with up to `window` records on either side of them, so the output stays
+/// small for large RHS. A sorted RHS that isn't actually sorted according to the predicate will
+/// show up here as results that are out of order.
+pub fn explain_join<'a, L, R, P>(
+    left_sample: &L,
+    rhs: impl Into<RHS<'a, R>>,
+    predicate: P,
+    window: usize,
+) -> Vec<(usize, core::cmp::Ordering)>
+where
+    R: 'a,
+    P: Fn(&L, &R) -> core::cmp::Ordering,
+{
+    let rhs = rhs.into();
+    let (start, end) = if rhs.is_sorted() {
+        let (lower, upper) = rhs.get_bounds(left_sample, &predicate);
+        (
+            lower.saturating_sub(window),
+            upper.saturating_add(window).min(rhs.len()),
+        )
+    } else {
+        (0, rhs.len())
+    };
+
+    (start..end)
+        .filter_map(|i| Some((i, predicate(left_sample, rhs.get(i)?))))
+        .collect()
+}
+
+/// An inconsistency found while checking that a [sorted](RHS::Sorted) RHS is actually sorted
+/// according to the join predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortError {
+    /// The position in RHS whose comparison was inconsistent with a binary search result.
+    pub rhs_index: usize,
+}
+
+impl core::fmt::Display for SortError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RHS is not sorted according to the join predicate at index {}",
+            self.rhs_index
+        )
+    }
+}
+
+impl core::error::Error for SortError {}
+
 /// A wrapper around the right-hand side of your join.
+///
+/// Every variant borrows its values, so an RHS is [Copy] and can be built once and passed to any
+/// number of joins. To keep right values alongside the joins that borrow them, see [OwnedRHS].
 pub enum RHS<'a, R> {
     /// Input which is not (necessarily) sorted. Searches of RHS will be O(n).
     ///
@@ -10,6 +89,286 @@ pub enum RHS<'a, R> {
     ///
     /// This variant is explicitly created with [RHS::new_sorted]
     Sorted(&'a [R]),
+
+    /// Input which arrives in several chunks (eg, from batched reads) and is not (necessarily)
+    /// sorted. The chunks are searched as if they were concatenated, in O(n).
+    ///
+    /// This variant is explicitly created with [RHS::new_chunks]
+    Chunks(&'a [&'a [R]]),
+
+    /// Input which arrives in several chunks that are known to be sorted according to the join
+    /// predicate. Each chunk must be sorted, and the chunks themselves must be in order: every
+    /// value in chunk `i` must sort at or before every value in chunk `i + 1`. Searches of RHS
+    /// will be O(lg n) plus a walk over the chunks.
+    ///
+    /// This variant is explicitly created with [RHS::new_sorted_chunks]
+    SortedChunks(&'a [&'a [R]]),
+
+    /// Input which is not sorted, but will be sorted incrementally as searches require. This is an
+    /// advanced optimization for joins that only touch a fraction of RHS, such as when LHS is in
+    /// ascending order and only covers the lowest keys in RHS.
+    ///
+    /// See [PartialSort] for details. This variant is explicitly created with
+    /// [RHS::new_partially_sorted]
+    PartiallySorted(&'a PartialSort<'a, R>),
+}
+
+// Implemented by hand, as deriving would require `R: Clone`
+impl<R> Clone for RHS<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for RHS<'_, R> {}
+
+/// Compares two right-hand side values for a [PartialSort].
+type SortOrder<'a, R> = dyn Fn(&R, &R) -> core::cmp::Ordering + 'a;
+
+/// An unsorted slice which is sorted incrementally, only as far as searches of it require.
+///
+/// The slice itself is left untouched; instead, a permutation of its indices is sorted. Each
+/// search first extends the sorted prefix of that permutation, in chunks, until it is known to
+/// contain every value the search could match (using [slice::select_nth_unstable_by] to find each
+/// chunk), then binary searches the prefix.
+///
+/// The sort order given by `cmp` must be consistent with the join predicate. Because the sort is
+/// unstable, values that compare equal are not necessarily yielded in their original order.
+pub struct PartialSort<'a, R> {
+    values: &'a [R],
+    cmp: Box<SortOrder<'a, R>>,
+
+    /// Indices into `values`, of which the first `sorted` are in sorted order and sort at or
+    /// before all the rest
+    order: RefCell<Vec<usize>>,
+    sorted: Cell<usize>,
+}
+
+impl<'a, R> PartialSort<'a, R> {
+    /// The smallest number of values sorted at a time
+    const MIN_CHUNK: usize = 16;
+
+    /// Wraps `values`, which will be sorted according to `cmp` as needed.
+    pub fn new<C>(values: &'a [R], cmp: C) -> Self
+    where
+        C: Fn(&R, &R) -> core::cmp::Ordering + 'a,
+    {
+        Self {
+            values,
+            cmp: Box::new(cmp),
+            order: RefCell::new((0..values.len()).collect()),
+            sorted: Cell::new(0),
+        }
+    }
+
+    /// The number of values sorted so far.
+    pub fn sorted_len(&self) -> usize {
+        self.sorted.get()
+    }
+
+    /// The wrapped values, in their original order.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn values(&self) -> &'a [R] {
+        self.values
+    }
+
+    fn get(&self, i: usize) -> Option<&'a R> {
+        let order = self.order.borrow();
+        order.get(i).map(|&j| &self.values[j])
+    }
+
+    /// The index in `values` of the value at position `i` in the sorted order.
+    fn original_index(&self, i: usize) -> usize {
+        self.order.borrow()[i]
+    }
+
+    /// Sorts the next chunk of values, doubling the size of the sorted prefix.
+    fn sort_chunk(&self) {
+        let mut order = self.order.borrow_mut();
+        let sorted = self.sorted.get();
+        let chunk = sorted.max(Self::MIN_CHUNK).min(order.len() - sorted);
+
+        let cmp = |a: &usize, b: &usize| (self.cmp)(&self.values[*a], &self.values[*b]);
+        let rest = &mut order[sorted..];
+        if chunk < rest.len() {
+            rest.select_nth_unstable_by(chunk - 1, cmp);
+        }
+        rest[..chunk].sort_unstable_by(cmp);
+
+        self.sorted.set(sorted + chunk);
+    }
+
+    fn partition_point<F>(&self, pred: F) -> usize
+    where
+        F: Fn(&R) -> bool,
+    {
+        // Every unsorted value sorts at or after the last sorted one, so once that fails the
+        // predicate, the partition point is within the sorted prefix
+        while self.sorted.get() < self.values.len()
+            && (self.sorted.get() == 0 || self.get(self.sorted.get() - 1).is_some_and(&pred))
+        {
+            self.sort_chunk();
+        }
+
+        let order = self.order.borrow();
+        order[..self.sorted.get()].partition_point(|&j| pred(&self.values[j]))
+    }
+}
+
+/// A right-hand side indexed by a hash of a key extracted from each record, for amortized O(1)
+/// searches.
+///
+/// Because the index is built on a key rather than an `Ordering` predicate, it is joined against
+/// with [JoinableByKey](crate::JoinableByKey) methods such as
+/// [inner_join_hashed](crate::JoinableByKey::inner_join_hashed), which extract the same key from
+/// each left record. The index can be reused for any number of joins.
+#[cfg(feature = "std")]
+pub struct HashedRHS<'a, R, K> {
+    values: &'a [R],
+
+    /// The indices in `values` having each key, in order
+    index: HashMap<K, Vec<usize>>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R, K> HashedRHS<'a, R, K>
+where
+    K: Hash + Eq,
+{
+    /// Indexes `values` by the key returned for each by `key`.
+    pub fn new<F>(values: &'a [R], key: F) -> Self
+    where
+        F: Fn(&R) -> K,
+    {
+        let mut index: HashMap<K, Vec<usize>> = HashMap::new();
+        for (i, r) in values.iter().enumerate() {
+            index.entry(key(r)).or_default().push(i);
+        }
+
+        Self { values, index }
+    }
+
+    /// The number of values indexed.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there are no values indexed.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the indices of the values having `key`, in order.
+    pub(crate) fn indices(&self, key: &K) -> &[usize] {
+        self.index.get(key).map_or(&[], |is| is.as_slice())
+    }
+
+    pub(crate) fn get(&self, i: usize) -> Option<&'a R> {
+        self.values.get(i)
+    }
+}
+
+/// A right-hand side that owns its values, for when there's no other binding to keep them alive.
+///
+/// Joins yield references into RHS that live as long as it does, and an iterator can't yield
+/// references into itself, so a join can't take ownership of the values. Instead, `OwnedRHS` holds
+/// them and joins borrow from it: pass `&owned` wherever an [RHS] is expected. Results then live
+/// as long as the `OwnedRHS`, which can be returned from or stored by builder-style code.
+pub struct OwnedRHS<R> {
+    values: Vec<R>,
+    sorted: bool,
+}
+
+impl<R> OwnedRHS<R> {
+    /// Takes ownership of the given values, which will be searched linearly.
+    pub fn new(values: Vec<R>) -> Self {
+        Self {
+            values,
+            sorted: false,
+        }
+    }
+
+    /// Takes ownership of the given values, which are assumed to be sorted according to how they
+    /// will be searched, as for [RHS::new_sorted].
+    pub fn new_sorted(values: Vec<R>) -> Self {
+        Self {
+            values,
+            sorted: true,
+        }
+    }
+
+    /// Clones the given values and sorts them with `compare`, which must be consistent with how
+    /// they will be searched, so that they can be binary searched.
+    ///
+    /// Unlike [RHS::new_sorted], this can't silently give wrong results because the values weren't
+    /// actually sorted. The sort is stable.
+    pub fn new_sorted_by<F>(values: &[R], compare: F) -> Self
+    where
+        R: Clone,
+        F: FnMut(&R, &R) -> core::cmp::Ordering,
+    {
+        let mut values = values.to_vec();
+        values.sort_by(compare);
+        Self::new_sorted(values)
+    }
+
+    /// Clones the given values and sorts them by the key returned by `key`, which must be the key
+    /// that they will be searched by. See [OwnedRHS::new_sorted_by].
+    pub fn new_sorted_by_key<K, F>(values: &[R], key: F) -> Self
+    where
+        R: Clone,
+        K: Ord,
+        F: FnMut(&R) -> K,
+    {
+        let mut values = values.to_vec();
+        values.sort_by_key(key);
+        Self::new_sorted(values)
+    }
+
+    /// Drains `values`, such as records read from a file that can only be consumed once, and
+    /// sorts them with `compare`, which must be consistent with how they will be searched.
+    ///
+    /// This is eager: the whole iterator is buffered into a newly allocated `Vec` before any join
+    /// runs. Use [FromIterator] (eg, [Iterator::collect]) to buffer the values without sorting
+    /// them. The sort is stable.
+    pub fn from_iter_sorted<I, F>(values: I, compare: F) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        F: FnMut(&R, &R) -> core::cmp::Ordering,
+    {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_by(compare);
+        Self::new_sorted(values)
+    }
+
+    /// Returns the values, giving up ownership of them.
+    pub fn into_inner(self) -> Vec<R> {
+        self.values
+    }
+}
+
+impl<R> From<Vec<R>> for OwnedRHS<R> {
+    fn from(values: Vec<R>) -> Self {
+        OwnedRHS::new(values)
+    }
+}
+
+/// Eagerly buffers every value into a newly allocated `Vec`, which will be searched linearly. See
+/// [OwnedRHS::from_iter_sorted] to sort them as well.
+impl<R> FromIterator<R> for OwnedRHS<R> {
+    fn from_iter<I: IntoIterator<Item = R>>(values: I) -> Self {
+        OwnedRHS::new(values.into_iter().collect())
+    }
+}
+
+impl<'a, R> From<&'a OwnedRHS<R>> for RHS<'a, R> {
+    fn from(rhs: &'a OwnedRHS<R>) -> Self {
+        if rhs.sorted {
+            RHS::Sorted(&rhs.values)
+        } else {
+            RHS::Unsorted(&rhs.values)
+        }
+    }
 }
 
 impl<'a, R> From<&'a [R]> for RHS<'a, R> {
@@ -18,6 +377,12 @@ impl<'a, R> From<&'a [R]> for RHS<'a, R> {
     }
 }
 
+impl<'a, R, const N: usize> From<&'a [R; N]> for RHS<'a, R> {
+    fn from(rhs: &'a [R; N]) -> Self {
+        RHS::Unsorted(rhs)
+    }
+}
+
 impl<'a, R> RHS<'a, R> {
     /// Create a new RHS from the given slice.
     ///
@@ -29,54 +394,267 @@ impl<'a, R> RHS<'a, R> {
     /// Create a new RHS from the given slice where records are assumed to be sorted
     /// according to how they will be searched.
     ///
-    /// Provided records will be binary searched, yielding faster searches.
+    /// Provided records will be binary searched, yielding faster searches. If they aren't sorted,
+    /// joins will miss matches; in debug builds, a search that finds values out of order panics,
+    /// naming the offending index. [OwnedRHS::new_sorted_by] sorts a copy instead.
     pub fn new_sorted(rhs: &'a [R]) -> Self {
         RHS::Sorted(rhs)
     }
 
+    /// Create a new RHS from the given chunks, which are joined against as if they were
+    /// concatenated.
+    ///
+    /// Provided records will be searched linearly.
+    pub fn new_chunks(chunks: &'a [&'a [R]]) -> Self {
+        RHS::Chunks(chunks)
+    }
+
+    /// Create a new RHS from the given chunks where records are assumed to be sorted according
+    /// to how they will be searched, both within each chunk and across chunks.
+    ///
+    /// Provided records will be binary searched, yielding faster searches.
+    pub fn new_sorted_chunks(chunks: &'a [&'a [R]]) -> Self {
+        RHS::SortedChunks(chunks)
+    }
+
+    /// Create a new RHS which sorts the given values incrementally, as searches require.
+    ///
+    /// Provided records will be binary searched once sorted. See [PartialSort].
+    pub fn new_partially_sorted(rhs: &'a PartialSort<'a, R>) -> Self {
+        RHS::PartiallySorted(rhs)
+    }
+
+    /// Whether values are known to be sorted, and so can be binary searched.
+    pub(crate) fn is_sorted(&self) -> bool {
+        match self {
+            RHS::Unsorted(_) | RHS::Chunks(_) => false,
+            RHS::Sorted(_) | RHS::SortedChunks(_) | RHS::PartiallySorted(_) => true,
+        }
+    }
+
+    /// The total number of values, across every chunk for chunked RHS.
+    pub fn len(&self) -> usize {
+        match *self {
+            RHS::Unsorted(rs) | RHS::Sorted(rs) => rs.len(),
+            RHS::Chunks(cs) | RHS::SortedChunks(cs) => cs.iter().map(|c| c.len()).sum(),
+            RHS::PartiallySorted(ps) => ps.values.len(),
+        }
+    }
+
+    /// Whether there are no values, in which case inner joins yield nothing.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value at index `i`, if any. For chunked RHS, `i` indexes the chunks as if they
+    /// were concatenated.
+    pub(crate) fn get(&self, i: usize) -> Option<&'a R> {
+        match *self {
+            RHS::Unsorted(rs) | RHS::Sorted(rs) => rs.get(i),
+            RHS::Chunks(cs) | RHS::SortedChunks(cs) => {
+                let mut i = i;
+                for c in cs {
+                    if i < c.len() {
+                        return Some(&c[i]);
+                    }
+                    i -= c.len();
+                }
+
+                None
+            }
+            RHS::PartiallySorted(ps) => ps.get(i),
+        }
+    }
+
+    /// Returns the position in the original values of the value at index `i`, as returned by
+    /// [RHS::next_match]. Chunks are numbered as if they were concatenated.
+    pub(crate) fn original_index(&self, i: usize) -> usize {
+        match *self {
+            RHS::PartiallySorted(ps) => ps.original_index(i),
+            _ => i,
+        }
+    }
+
+    /// Returns the value at position `i` in the original values, as numbered by
+    /// [RHS::original_index].
+    pub(crate) fn get_original(&self, i: usize) -> Option<&'a R> {
+        match *self {
+            RHS::PartiallySorted(ps) => ps.values.get(i),
+            _ => self.get(i),
+        }
+    }
+
+    /// Returns the index of the first value for which `pred` is false, assuming the values are
+    /// partitioned such that `pred` is true for all values before it, as [slice::partition_point]
+    /// does.
+    pub(crate) fn partition_point<F>(&self, pred: F) -> usize
+    where
+        F: Fn(&R) -> bool,
+    {
+        match *self {
+            RHS::Unsorted(rs) | RHS::Sorted(rs) => rs.partition_point(pred),
+            RHS::Chunks(cs) | RHS::SortedChunks(cs) => {
+                // Walk to the first chunk whose last value fails the predicate; the partition
+                // point is within it. Empty chunks are skipped.
+                let mut offset = 0;
+                for c in cs {
+                    match c.last() {
+                        Some(last) if !pred(last) => return offset + c.partition_point(pred),
+                        _ => offset += c.len(),
+                    }
+                }
+
+                offset
+            }
+            RHS::PartiallySorted(ps) => ps.partition_point(pred),
+        }
+    }
+
     pub(crate) fn has_value<L, P>(&self, l: &L, predicate: P) -> bool
     where
-        P: Fn(&L, &R) -> std::cmp::Ordering,
+        P: Fn(&L, &R) -> core::cmp::Ordering,
     {
-        match self {
-            RHS::Unsorted(rs) => rs.iter().any(|r| (predicate)(l, r).is_eq()),
-            RHS::Sorted(rs) => rs.binary_search_by(|r| (predicate)(l, r)).is_ok(),
+        let mut range = self.get_range(l, &predicate);
+        self.next_match(l, &predicate, &mut range).is_some()
+    }
+
+    /// Returns the range of indices that may match `left`. For unsorted RHS, this is every index;
+    /// for sorted RHS, every value in the range is known to match.
+    ///
+    /// In debug builds, each search of a sorted RHS checks that the values at and around the range
+    /// are consistent with the predicate, panicking with the offending index if they aren't.
+    pub(crate) fn get_range<L, P>(&self, left: &L, predicate: &P) -> (usize, usize)
+    where
+        P: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        if !self.is_sorted() {
+            return (0, self.len());
         }
+
+        let range = self.get_bounds(left, predicate);
+
+        #[cfg(debug_assertions)]
+        if let Err(e) = self.check_bounds(left, predicate, range) {
+            panic!("{e}; sorted RHS must be sorted consistently with the join predicate");
+        }
+
+        range
     }
 
-    pub(crate) fn get_range<L, P>(&'a self, left: &L, predicate: &P) -> (usize, usize)
+    /// For sorted RHS, returns the range of indices whose values compare equal to `left`. When
+    /// there's no match, both ends are the position where `left` would be inserted.
+    ///
+    /// The range is always within RHS, even if the predicate is inconsistent with the sort order.
+    pub(crate) fn get_bounds<L, P>(&self, left: &L, predicate: &P) -> (usize, usize)
     where
-        P: Fn(&L, &R) -> std::cmp::Ordering,
+        P: Fn(&L, &R) -> core::cmp::Ordering,
     {
-        match self {
-            RHS::Unsorted(rs) => (0, rs.len()),
-            RHS::Sorted(rs) => {
-                if let Ok(pos) = rs.binary_search_by(|r| (predicate)(left, r).reverse()) {
-                    // We found *a* match, but it may not be the first one
-                    let mut start = pos;
-                    while start > 0 && (predicate)(left, &rs[start - 1]).is_eq() {
-                        start -= 1;
-                    }
+        let len = self.len();
+        let lower = self.partition_point(|r| (predicate)(left, r).is_gt());
+        let upper = self.partition_point(|r| !(predicate)(left, r).is_lt());
+        let lower = lower.min(len);
+        (lower, upper.clamp(lower, len))
+    }
 
-                    // Same for the end
-                    let mut end = pos;
-                    while end < rs.len() && (predicate)(left, &rs[end]).is_eq() {
-                        end += 1;
-                    }
+    /// Checks that `range`, as returned by [RHS::get_bounds], is consistent with the predicate: its
+    /// endpoints compare equal to `left`, the value before it compares less, and the value after
+    /// it compares greater.
+    pub(crate) fn check_bounds<L, P>(
+        &self,
+        left: &L,
+        predicate: &P,
+        range: (usize, usize),
+    ) -> Result<(), SortError>
+    where
+        P: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        use core::cmp::Ordering;
 
-                    //panic!("sorted range from pos={pos}: ({start}, {end})"); // (4,6)
+        let (lower, upper) = range;
+        let expect = |i: usize, ordering: Ordering| match self.get(i) {
+            Some(r) if (predicate)(left, r) != ordering => Err(SortError { rhs_index: i }),
+            _ => Ok(()),
+        };
 
-                    (start, end)
-                } else {
-                    // No match found; values here don't matter except that the range must be empty
-                    (1, 0)
-                }
+        if lower < upper {
+            expect(lower, Ordering::Equal)?;
+            expect(upper - 1, Ordering::Equal)?;
+        }
+        if lower > 0 {
+            expect(lower - 1, Ordering::Greater)?;
+        }
+        expect(upper, Ordering::Less)
+    }
+
+    /// Counts the matches for `left`. For sorted RHS, this is the width of its range, without
+    /// visiting the matches.
+    pub(crate) fn count_matches<L, P>(&self, left: &L, predicate: &P) -> usize
+    where
+        P: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        let mut range = self.get_range(left, predicate);
+        if self.is_sorted() {
+            return range.1 - range.0;
+        }
+
+        let mut count = 0;
+        while self.next_match(left, predicate, &mut range).is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    /// Pushes every match for `left` onto `out`, in RHS order.
+    ///
+    /// For sorted RHS, the number of matches is known up front, so `out` grows at most once.
+    pub(crate) fn push_matches<L, P>(&self, left: &L, predicate: &P, out: &mut Vec<&'a R>)
+    where
+        P: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        let mut range = self.get_range(left, predicate);
+        if self.is_sorted() {
+            out.reserve(range.1 - range.0);
+        }
+        while let Some((_, r)) = self.next_match(left, predicate, &mut range) {
+            out.push(r);
+        }
+    }
+
+    /// Finds the next match for `left` within `range` (as returned by [RHS::get_range]), advancing
+    /// the start of the range past it. Returns the index of the match along with the value.
+    pub(crate) fn next_match<L, P>(
+        &self,
+        left: &L,
+        predicate: &P,
+        range: &mut (usize, usize),
+    ) -> Option<(usize, &'a R)>
+    where
+        P: Fn(&L, &R) -> core::cmp::Ordering,
+    {
+        while range.0 < range.1 {
+            let i = range.0;
+            range.0 += 1;
+
+            let Some(r) = self.get(i) else {
+                // The range extends past RHS, so nothing more can match
+                range.0 = range.1;
+                return None;
+            };
+
+            // Every value in a sorted range is already known to match
+            if self.is_sorted() || (predicate)(left, r).is_eq() {
+                return Some((i, r));
             }
         }
+
+        None
     }
 }
 
+// Sorted RHS used to be searched with the predicate reversed here; it is now rejected
+#[cfg(debug_assertions)]
 #[test]
+#[should_panic(expected = "RHS is not sorted according to the join predicate")]
 fn test_has_value_sorted() {
     use crate::joined_grouped::JoinableGrouped;
     let left = [1, 2, 3];
@@ -94,6 +672,61 @@ fn test_has_value_sorted() {
     assert_eq!(joined.next(), Some(&(2, "!")));
 }
 
+#[test]
+fn test_sorted_predicate_direction() {
+    use crate::{Joinable, JoinableGrouped};
+    let left = [1, 2, 3];
+    let right = [(1, "hello"), (2, "world"), (2, "!")];
+    // Every join takes the same left-to-right predicate over a sorted RHS
+    let rhs = RHS::new_sorted(&right);
+    assert!(rhs.has_value(&2, |l: &i32, r: &(i32, &str)| l.cmp(&r.0)));
+    assert!(!rhs.has_value(&3, |l: &i32, r: &(i32, &str)| l.cmp(&r.0)));
+
+    let semi = left
+        .iter()
+        .semi_join(rhs, |l, r| (*l).cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(semi, vec![&1, &2]);
+
+    let anti = left
+        .iter()
+        .anti_join(rhs, |l, r| (*l).cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(anti, vec![&3]);
+
+    let grouped = left
+        .iter()
+        .inner_join_grouped(rhs, |l, r| (*l).cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        grouped,
+        vec![
+            (&1, vec![&(1, "hello")]),
+            (&2, vec![&(2, "world"), &(2, "!")])
+        ]
+    );
+
+    let inner = left.iter().inner_join(rhs, |l, r| l.cmp(&r.0)).count();
+    assert_eq!(inner, 3);
+}
+
+#[test]
+fn test_copy() {
+    use crate::{Joinable, JoinableGrouped};
+
+    // Not Clone, which RHS doesn't require
+    struct Order(i32);
+
+    let orders = [Order(1), Order(3), Order(3)];
+    let right = RHS::new_sorted(&orders);
+    let predicate = |l: &i32, r: &Order| l.cmp(&r.0);
+
+    let inner = [1, 2, 3].iter().inner_join(right, predicate).count();
+    let semi = [3, 4].into_iter().semi_join(right, predicate).count();
+    assert_eq!((inner, semi), (3, 1));
+    assert!(right.is_sorted());
+}
+
 #[test]
 fn test_has_value_unsorted() {
     use crate::JoinableGrouped;
@@ -110,3 +743,264 @@ fn test_has_value_unsorted() {
     assert_eq!(joined.next(), Some(&(1, "hello")));
     assert_eq!(joined.next(), Some(&(2, "world")));
 }
+
+#[test]
+fn test_chunks() {
+    use crate::Joinable;
+    let left = [1, 2, 3, 4];
+
+    let first = [(2, "dos"), (1, "un"), (2, "deux")];
+    let second = [(3, "trois"), (2, "two")];
+    let chunks = [&first[..], &[], &second[..]];
+
+    let joined = left
+        .iter()
+        .inner_join(RHS::new_chunks(&chunks), |l, r| l.cmp(&r.0))
+        .map(|(_, r)| r.1)
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined, vec!["un", "dos", "deux", "two", "trois"]);
+}
+
+#[test]
+fn test_sorted_chunks() {
+    use crate::{Joinable, JoinableGrouped};
+    let left = [0, 1, 2, 3, 4];
+
+    // The group for key 2 is split across the chunk boundary
+    let first = [(1, "un"), (2, "dos")];
+    let second = [(2, "deux"), (2, "two"), (3, "trois")];
+    let chunks = [&first[..], &second[..]];
+    let rhs = || RHS::new_sorted_chunks(&chunks);
+
+    let joined = left
+        .iter()
+        .inner_join(rhs(), |l, r| l.cmp(&r.0))
+        .map(|(l, r)| (*l, r.1))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        joined,
+        vec![(1, "un"), (2, "dos"), (2, "deux"), (2, "two"), (3, "trois")]
+    );
+
+    let semi = left
+        .iter()
+        .semi_join(rhs(), |l, r| (*l).cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(semi, vec![&1, &2, &3]);
+
+    let anti = left
+        .iter()
+        .anti_join(rhs(), |l, r| (*l).cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(anti, vec![&0, &4]);
+}
+
+#[test]
+fn test_partially_sorted() {
+    use crate::Joinable;
+    let left = [2, 3, 3, 5];
+
+    // Keys 0..100, with key 3 duplicated, in a scrambled order
+    let mut right = (0..100).map(|i| (i * 37 % 100, i)).collect::<Vec<_>>();
+    right.push((3, 100));
+
+    let partial = PartialSort::new(&right, |a, b| a.0.cmp(&b.0));
+    let mut joined = left
+        .iter()
+        .inner_join(RHS::new_partially_sorted(&partial), |l, r| l.cmp(&r.0))
+        .map(|(l, r)| (*l, r.0))
+        .collect::<Vec<_>>();
+    joined.sort();
+
+    assert_eq!(joined, vec![(2, 2), (3, 3), (3, 3), (3, 3), (3, 3), (5, 5)]);
+    assert!(partial.sorted_len() < right.len());
+}
+
+#[test]
+fn test_point_join() {
+    let right = [(1, "un"), (2, "deux"), (2, "dos"), (4, "quatre")];
+
+    let matches = point_join(&2, RHS::new_sorted(&right), |l, r| l.cmp(&r.0));
+    assert_eq!(matches, vec![&(2, "deux"), &(2, "dos")]);
+
+    let matches = point_join(&2, &right[..], |l, r| l.cmp(&r.0));
+    assert_eq!(matches, vec![&(2, "deux"), &(2, "dos")]);
+
+    assert!(point_join(&3, RHS::new_sorted(&right), |l, r| l.cmp(&r.0)).is_empty());
+}
+
+#[test]
+fn test_explain_join() {
+    use core::cmp::Ordering::*;
+    let right = [
+        (1, "un"),
+        (2, "deux"),
+        (2, "dos"),
+        (4, "quatre"),
+        (5, "cinq"),
+    ];
+
+    let explained = explain_join(&2, &right[..], |l, r| l.cmp(&r.0), 0);
+    assert_eq!(
+        explained,
+        vec![(0, Greater), (1, Equal), (2, Equal), (3, Less), (4, Less)]
+    );
+
+    let explained = explain_join(&2, RHS::new_sorted(&right), |l, r| l.cmp(&r.0), 1);
+    assert_eq!(
+        explained,
+        vec![(0, Greater), (1, Equal), (2, Equal), (3, Less)]
+    );
+
+    // No match: the window is centered on where 3 would be inserted
+    let explained = explain_join(&3, RHS::new_sorted(&right), |l, r| l.cmp(&r.0), 1);
+    assert_eq!(explained, vec![(2, Greater), (3, Less)]);
+}
+
+#[test]
+fn test_next_match_out_of_bounds() {
+    let right = [1, 2, 3];
+    let rhs = RHS::new_sorted(&right);
+    let predicate = |l: &i32, r: &i32| l.cmp(r);
+
+    // A range extending past RHS yields what's there, then stops
+    let mut range = (1, 10);
+    assert_eq!(rhs.next_match(&2, &predicate, &mut range), Some((1, &2)));
+    assert_eq!(rhs.next_match(&2, &predicate, &mut range), Some((2, &3)));
+    assert_eq!(rhs.next_match(&2, &predicate, &mut range), None);
+    assert_eq!(range.0, range.1);
+
+    let everything = |_: &i32, _: &i32| core::cmp::Ordering::Equal;
+    assert_eq!(rhs.get_bounds(&0, &everything), (0, 3));
+}
+
+#[test]
+fn test_owned() {
+    use crate::{Joinable, JoinableGrouped};
+
+    fn orders() -> OwnedRHS<(i32, &'static str)> {
+        OwnedRHS::new_sorted(vec![(1, "apple"), (1, "avocado"), (3, "cherry")])
+    }
+
+    let right = orders();
+    let predicate = |l: &i32, r: &(i32, &str)| l.cmp(&r.0);
+    assert!(RHS::from(&right).has_value(&3, predicate));
+    assert_eq!(RHS::from(&right).get_range(&1, &predicate), (0, 2));
+
+    let left = [1, 2, 3];
+    let joined = left
+        .iter()
+        .inner_join(&right, |l, r| l.cmp(&r.0))
+        .map(|(_, r)| r.1)
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec!["apple", "avocado", "cherry"]);
+
+    let right = OwnedRHS::from(vec![(3, "cherry"), (1, "apple")]);
+    let anti = left
+        .into_iter()
+        .anti_join(&right, predicate)
+        .collect::<Vec<_>>();
+    assert_eq!(anti, vec![2]);
+    assert_eq!(right.into_inner().len(), 2);
+}
+
+#[test]
+fn test_owned_sorted_by() {
+    use crate::Joinable;
+
+    let left = [1, 2];
+    let right = [(2, "deux"), (1, "un"), (2, "dos")];
+
+    let by = OwnedRHS::new_sorted_by(&right, |a, b| a.0.cmp(&b.0));
+    let by_key = OwnedRHS::new_sorted_by_key(&right, |r| r.0);
+
+    for rhs in [&by, &by_key] {
+        let joined = left
+            .iter()
+            .inner_join(rhs, |l, r| l.cmp(&r.0))
+            .map(|(_, r)| r.1)
+            .collect::<Vec<_>>();
+        assert_eq!(joined, vec!["un", "deux", "dos"]);
+    }
+}
+
+#[test]
+fn test_len() {
+    let right = [1, 2, 3];
+    assert_eq!(RHS::new_unsorted(&right).len(), 3);
+    assert_eq!(RHS::new_sorted(&right[..0]).len(), 0);
+    assert!(RHS::new_sorted(&right[..0]).is_empty());
+
+    let chunks = [&right[..1], &[], &right[1..]];
+    assert_eq!(RHS::new_chunks(&chunks).len(), 3);
+    assert!(!RHS::new_sorted_chunks(&chunks).is_empty());
+
+    let partial = PartialSort::new(&right, |a, b| a.cmp(b));
+    assert_eq!(RHS::new_partially_sorted(&partial).len(), 3);
+
+    let owned = OwnedRHS::new(vec![1]);
+    assert_eq!(RHS::from(&owned).len(), 1);
+}
+
+#[test]
+fn test_from_array() {
+    use crate::Joinable;
+
+    let left = [1, 2];
+    let right = [(2, "deux"), (1, "un")];
+
+    let joined = left
+        .iter()
+        .inner_join(&right, |l, r| l.cmp(&r.0))
+        .collect::<Vec<_>>();
+    let sliced = left
+        .iter()
+        .inner_join(&right[..], |l, r| l.cmp(&r.0))
+        .collect::<Vec<_>>();
+    assert_eq!(joined, sliced);
+    assert!(!RHS::from(&right).is_sorted());
+}
+
+#[test]
+fn test_owned_from_iter() {
+    use crate::Joinable;
+
+    let left = [1, 2];
+
+    // Stands in for a source that can only be read once
+    let read = || {
+        "2 deux\n1 un\n2 dos".lines().map(|line| {
+            let (n, name) = line.split_once(' ').unwrap();
+            (n.parse::<i32>().unwrap(), name)
+        })
+    };
+
+    let unsorted = read().collect::<OwnedRHS<_>>();
+    let sorted = OwnedRHS::from_iter_sorted(read(), |a, b| a.0.cmp(&b.0));
+    assert!(!RHS::from(&unsorted).is_sorted());
+    assert!(RHS::from(&sorted).is_sorted());
+
+    for rhs in [&unsorted, &sorted] {
+        let joined = left
+            .iter()
+            .inner_join(rhs, |l, r| l.cmp(&r.0))
+            .map(|(_, r)| r.1)
+            .collect::<Vec<_>>();
+        assert_eq!(joined, vec!["un", "deux", "dos"]);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not sorted according to the join predicate at index 1")]
+fn test_unsorted_sorted_rhs() {
+    use crate::Joinable;
+
+    // Searching for 2 lands on the 3 before it, which doesn't compare equal
+    let right = [1, 3, 2, 4, 5];
+    [2].iter()
+        .inner_join(RHS::new_sorted(&right), |l, r| l.cmp(r))
+        .for_each(drop);
+}
@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// One result of a grouped join, which serializes as `{ "left": ..., "right": [...] }`.
+///
+/// These are yielded by the `serializable` method of the results of
+/// [grouped joins](crate::JoinableGrouped).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupedRow<'a, L, R>(pub L, pub Vec<&'a R>);
+
+impl<'a, L, R> From<(L, Vec<&'a R>)> for GroupedRow<'a, L, R> {
+    fn from((left, right): (L, Vec<&'a R>)) -> Self {
+        GroupedRow(left, right)
+    }
+}
+
+impl<L, R> Serialize for GroupedRow<'_, L, R>
+where
+    L: Serialize,
+    R: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut row = serializer.serialize_struct("GroupedRow", 2)?;
+        row.serialize_field("left", &self.0)?;
+        row.serialize_field("right", &self.1)?;
+        row.end()
+    }
+}
+
+/// Wraps a grouped join, yielding each result as a [GroupedRow].
+#[must_use = "join adapters are lazy and do nothing unless consumed"]
+pub struct SerializableRows<I> {
+    pub(crate) inner: I,
+}
+
+impl<'a, I, L, R> Iterator for SerializableRows<I>
+where
+    I: Iterator<Item = (L, Vec<&'a R>)>,
+    R: 'a,
+{
+    type Item = GroupedRow<'a, L, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(GroupedRow::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[test]
+fn test_serializable() {
+    use crate::JoinableGrouped;
+    let left = [(1, "one"), (2, "two")];
+    let right = [(1, "un"), (1, "uno")];
+
+    let rows = left
+        .iter()
+        .outer_join_grouped(&right[..], |l, r| l.0.cmp(&r.0))
+        .serializable()
+        .collect::<Vec<_>>();
+
+    let json = serde_json::to_string(&rows).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"left":[1,"one"],"right":[[1,"un"],[1,"uno"]]},{"left":[2,"two"],"right":[]}]"#
+    );
+}